@@ -30,4 +30,88 @@ pub enum ErrorCode {
     InvalidReserveConfiguration,
     #[msg("Token supply does not match expected value")]
     TokenSupplyMismatch,
+    #[msg("Invalid vesting configuration: duration and claim interval must be positive and cliff non-negative")]
+    InvalidVestingConfiguration,
+    #[msg("Invalid creator allocation: creator_allocation_bps must be below 10000")]
+    InvalidAllocationConfiguration,
+    #[msg("Invalid launch cooldown: launch_cooldown_seconds must be non-negative")]
+    InvalidCooldownConfiguration,
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    #[msg("Token metadata name, symbol or URI exceeds the Metaplex length limit")]
+    InvalidTokenMetadata,
+    #[msg("Transaction deadline has passed")]
+    DeadlineExceeded,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Trading is halted for this bonding curve")]
+    TradingHalted,
+    #[msg("Bonding curve is in sells-only mode")]
+    SellsOnly,
+    #[msg("Invalid burn price configuration: min must not exceed max and cooldown must be non-negative")]
+    InvalidBurnPriceConfiguration,
+    #[msg("Burn price is outside the platform bounds")]
+    BurnPriceOutOfBounds,
+    #[msg("Burn price was updated too recently")]
+    BurnPriceCooldownNotElapsed,
+    #[msg("Premium content has expired")]
+    ContentExpired,
+    #[msg("Premium content has reached its unlock limit")]
+    ContentUnlockLimitReached,
+    #[msg("Pass duration must be positive")]
+    InvalidPassDuration,
+    #[msg("Invalid unstake cooldown: unstake_cooldown_seconds must be non-negative")]
+    InvalidStakingConfiguration,
+    #[msg("Stake amount must be greater than zero")]
+    InvalidStakeAmount,
+    #[msg("Staked tokens are still within the unstake cooldown")]
+    StakeLocked,
+    #[msg("Invalid initial buy cap: max_initial_buy_bps must not exceed 10000")]
+    InvalidInitialBuyConfiguration,
+    #[msg("Launch buy exceeds the platform cap on creator supply")]
+    InitialBuyExceedsCap,
+    #[msg("Invalid launch window: launch_window_seconds must be non-negative")]
+    InvalidLaunchWindowConfiguration,
+    #[msg("Buys during the launch window must pass the buyer's launch_buys account")]
+    LaunchBuysAccountRequired,
+    #[msg("Buy exceeds the per-wallet cap for the launch window")]
+    LaunchWalletCapExceeded,
+    #[msg("Buy exceeds the per-transaction SOL cap for the launch window")]
+    LaunchSolPerTxExceeded,
+    #[msg("Invalid referral fee: referral_fee_bps must not exceed 10000")]
+    InvalidReferralConfiguration,
+    #[msg("Traders cannot refer themselves")]
+    SelfReferral,
+    #[msg("Invalid curve configuration")]
+    InvalidCurveConfiguration,
+    #[msg("Curve kind is not allowed by the platform")]
+    CurveKindNotAllowed,
+    #[msg("Invalid supply configuration: check total supply and decimal bounds")]
+    InvalidSupplyConfiguration,
+    #[msg("Total supply or decimals outside platform limits")]
+    InvalidTokenSupply,
+    #[msg("Creator allocation or vesting schedule outside platform limits")]
+    InvalidVestingSchedule,
+    #[msg("Vesting beneficiaries must be unique, at most 5, with shares summing to 10000")]
+    InvalidBeneficiaries,
+    #[msg("Signer is not a beneficiary of this vesting allocation")]
+    NotABeneficiary,
+    #[msg("Fee split recipients must be unique, 1 to 5, with shares summing to 10000")]
+    InvalidFeeSplit,
+    #[msg("Creator fees are paid out through distribute_creator_fees once a fee split exists")]
+    FeeSplitActive,
+    #[msg("Pending fee split is still timelocked")]
+    FeeSplitTimelockActive,
+    #[msg("No pending fee split to apply")]
+    NoPendingFeeSplit,
+    #[msg("Remaining accounts must match the fee split recipients in order")]
+    FeeRecipientMismatch,
+    #[msg("Invalid fee split timelock: fee_split_timelock_seconds must be non-negative")]
+    InvalidFeeSplitConfiguration,
+    #[msg("Content expiry must be 0 or in the future")]
+    InvalidContentExpiry,
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
+    #[msg("A launch buy requires the creator's token account")]
+    CreatorTokenAccountRequired,
 }
@@ -0,0 +1,336 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{CurveKind, GlobalConfig, TradingStatus, WalletShare};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TradeSide {
+    Buy,
+    Sell,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FeeKind {
+    Platform,
+    Creator,
+}
+
+/// Emitted by initialize and update_config with the resulting config.
+#[event]
+pub struct ConfigUpdated {
+    pub authority: Pubkey,
+    pub fee_bps: u16,
+    pub platform_fee_bps: u16,
+    pub creator_fee_bps: u16,
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub vesting_cliff_seconds: i64,
+    pub vesting_duration_seconds: i64,
+    pub vesting_claim_interval_seconds: i64,
+    pub launch_cooldown_seconds: i64,
+    pub creator_allocation_bps: u16,
+    pub min_burn_sol_price: u64,
+    pub max_burn_sol_price: u64,
+    pub burn_price_update_cooldown_seconds: i64,
+    pub unstake_cooldown_seconds: i64,
+    pub max_initial_buy_bps: u16,
+    pub launch_window_seconds: i64,
+    pub launch_max_tokens_per_wallet: u64,
+    pub launch_max_sol_per_tx: u64,
+    pub referral_fee_bps: u16,
+    pub allowed_curve_kinds: u8,
+    pub min_total_supply: u64,
+    pub max_total_supply: u64,
+    pub min_token_decimals: u8,
+    pub max_token_decimals: u8,
+    pub max_creator_allocation_bps: u16,
+    pub min_vesting_cliff_seconds: i64,
+    pub min_vesting_duration_seconds: i64,
+    pub fee_split_timelock_seconds: i64,
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    /// Pubkey::default() when a pending transfer is cancelled
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub authority: Pubkey,
+    pub paused: bool,
+}
+
+#[event]
+pub struct TradingStatusUpdated {
+    pub token_mint: Pubkey,
+    pub authority: Pubkey,
+    pub trading_status: TradingStatus,
+}
+
+#[event]
+pub struct TokenCreated {
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub token_total_supply: u64,
+    pub decimals: u8,
+    /// Tokens available for sale on the curve
+    pub real_token_reserves: u64,
+    /// Tokens locked in the creator vesting vault
+    pub vesting_amount: u64,
+    pub vesting_cliff_seconds: i64,
+    pub vesting_duration_seconds: i64,
+    pub vesting_claim_interval_seconds: i64,
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub curve_kind: CurveKind,
+    /// Tokens the curve holds for sale at launch
+    pub curve_supply: u64,
+    /// Shaped-curve start and end prices, scaled by math::PRICE_SCALE (0 for constant product)
+    pub curve_start_price: u64,
+    pub curve_end_price: u64,
+    pub burn_sol_price: u64,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+/// Emitted by every buy and sell. Reserves and `tokens_sold` are post-trade
+/// values; with the curve shape from TokenCreated they give the curve's
+/// current price.
+#[event]
+pub struct Trade {
+    pub token_mint: Pubkey,
+    pub trader: Pubkey,
+    pub side: TradeSide,
+    /// Buy: total SOL paid including fees. Sell: net SOL received after fees.
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    /// Platform share net of any referral fee
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    /// Pubkey::default() when the trade had no referrer
+    pub referrer: Pubkey,
+    pub referral_fee: u64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub tokens_sold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BurnPriceUpdated {
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    pub previous_burn_sol_price: u64,
+    /// 0 when burns are disabled
+    pub burn_sol_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PremiumContentUpdated {
+    pub token_mint: Pubkey,
+    pub content_id: [u8; 32],
+    pub burn_sol_price: u64,
+    /// 0 = unlimited
+    pub max_unlocks: u32,
+    /// 0 = never expires
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct BurnedForAccess {
+    pub token_mint: Pubkey,
+    pub viewer: Pubkey,
+    pub content_id: [u8; 32],
+    pub tokens_burned: u64,
+    /// SOL-denominated price the burn was valued at
+    pub sol_value: u64,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub tokens_sold: u64,
+    pub token_total_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccessGifted {
+    pub token_mint: Pubkey,
+    /// Wallet whose tokens were burned
+    pub payer: Pubkey,
+    /// Wallet that receives access
+    pub beneficiary: Pubkey,
+    pub content_id: [u8; 32],
+    pub tokens_burned: u64,
+    /// SOL-denominated price the burn was valued at
+    pub sol_value: u64,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub tokens_sold: u64,
+    pub token_total_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PassTierUpdated {
+    pub token_mint: Pubkey,
+    pub tier: u8,
+    /// 0 when the tier is disabled
+    pub burn_sol_price: u64,
+    pub duration_seconds: i64,
+}
+
+#[event]
+pub struct AccessPassPurchased {
+    pub token_mint: Pubkey,
+    pub viewer: Pubkey,
+    pub tier: u8,
+    pub tokens_burned: u64,
+    /// SOL-denominated price the burn was valued at
+    pub sol_value: u64,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    /// Pass expiry after this purchase
+    pub expires_at: i64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub tokens_sold: u64,
+    pub token_total_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Staked {
+    pub token_mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    /// Position size after this stake
+    pub total_staked: u64,
+    /// Earliest time the position can be unstaked
+    pub unlocks_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Unstaked {
+    pub token_mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    /// Position size after this unstake
+    pub total_staked: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferrerRegistered {
+    pub authority: Pubkey,
+    pub referrer: Pubkey,
+}
+
+#[event]
+pub struct ReferralFeesClaimed {
+    pub authority: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub token_mint: Pubkey,
+    pub recipient: Pubkey,
+    pub kind: FeeKind,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeeSplitProposed {
+    pub token_mint: Pubkey,
+    pub recipients: Vec<WalletShare>,
+    /// When the split takes effect (immediately for a curve's first split)
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct FeeSplitApplied {
+    pub token_mint: Pubkey,
+    pub recipients: Vec<WalletShare>,
+}
+
+#[event]
+pub struct VestingClaimed {
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    /// Wallet that claimed
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    /// Cumulative amount claimed by this beneficiary after this claim
+    pub beneficiary_claimed_amount: u64,
+    /// Cumulative amount claimed across all beneficiaries after this claim
+    pub claimed_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingRevoked {
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    /// Unvested tokens burned by the revocation
+    pub tokens_burned: u64,
+    pub timestamp: i64,
+}
+
+impl From<&GlobalConfig> for ConfigUpdated {
+    fn from(config: &GlobalConfig) -> Self {
+        Self {
+            authority: config.authority,
+            fee_bps: config.fee_bps,
+            platform_fee_bps: config.platform_fee_bps,
+            creator_fee_bps: config.creator_fee_bps,
+            initial_virtual_token_reserves: config.initial_virtual_token_reserves,
+            initial_virtual_sol_reserves: config.initial_virtual_sol_reserves,
+            vesting_cliff_seconds: config.vesting_cliff_seconds,
+            vesting_duration_seconds: config.vesting_duration_seconds,
+            vesting_claim_interval_seconds: config.vesting_claim_interval_seconds,
+            launch_cooldown_seconds: config.launch_cooldown_seconds,
+            creator_allocation_bps: config.creator_allocation_bps,
+            min_burn_sol_price: config.min_burn_sol_price,
+            max_burn_sol_price: config.max_burn_sol_price,
+            burn_price_update_cooldown_seconds: config.burn_price_update_cooldown_seconds,
+            unstake_cooldown_seconds: config.unstake_cooldown_seconds,
+            max_initial_buy_bps: config.max_initial_buy_bps,
+            launch_window_seconds: config.launch_window_seconds,
+            launch_max_tokens_per_wallet: config.launch_max_tokens_per_wallet,
+            launch_max_sol_per_tx: config.launch_max_sol_per_tx,
+            referral_fee_bps: config.referral_fee_bps,
+            allowed_curve_kinds: config.allowed_curve_kinds,
+            min_total_supply: config.min_total_supply,
+            max_total_supply: config.max_total_supply,
+            min_token_decimals: config.min_token_decimals,
+            max_token_decimals: config.max_token_decimals,
+            max_creator_allocation_bps: config.max_creator_allocation_bps,
+            min_vesting_cliff_seconds: config.min_vesting_cliff_seconds,
+            min_vesting_duration_seconds: config.min_vesting_duration_seconds,
+            fee_split_timelock_seconds: config.fee_split_timelock_seconds,
+        }
+    }
+}
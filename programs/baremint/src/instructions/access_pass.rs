@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::events::{AccessPassPurchased, PassTierUpdated};
+use crate::instructions::burn_access::{settle_burn, BurnSettlement};
+use crate::state::{AccessPass, BondingCurve, GlobalConfig, PassTier, TradingStatus};
+
+// --- Set Pass Tier ---
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct SetPassTier<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [b"bonding_curve", token_mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        constraint = creator.key() == bonding_curve.creator @ ErrorCode::Unauthorized,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + PassTier::INIT_SPACE,
+        seeds = [b"pass_tier", token_mint.key().as_ref(), &[tier]],
+        bump,
+    )]
+    pub pass_tier: Account<'info, PassTier>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create or reprice a pass tier. A price of 0 disables the tier.
+pub fn handler_set_pass_tier(
+    ctx: Context<SetPassTier>,
+    tier: u8,
+    burn_sol_price: u64,
+    duration_seconds: i64,
+) -> Result<()> {
+    ctx.accounts
+        .global_config
+        .validate_burn_sol_price(burn_sol_price)?;
+    require!(duration_seconds > 0, ErrorCode::InvalidPassDuration);
+
+    let pass_tier = &mut ctx.accounts.pass_tier;
+    pass_tier.token_mint = ctx.accounts.token_mint.key();
+    pass_tier.tier = tier;
+    pass_tier.burn_sol_price = burn_sol_price;
+    pass_tier.duration_seconds = duration_seconds;
+    pass_tier.bump = ctx.bumps.pass_tier;
+
+    emit!(PassTierUpdated {
+        token_mint: pass_tier.token_mint,
+        tier,
+        burn_sol_price,
+        duration_seconds,
+    });
+
+    Ok(())
+}
+
+// --- Burn For Pass ---
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct BurnForPass<'info> {
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        constraint = !global_config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", token_mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.trading_status != TradingStatus::Halted @ ErrorCode::TradingHalted,
+        constraint = bonding_curve.trading_status != TradingStatus::SellsOnly @ ErrorCode::SellsOnly,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(mut)]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = viewer,
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"pass_tier", token_mint.key().as_ref(), &[tier]],
+        bump = pass_tier.bump,
+    )]
+    pub pass_tier: Account<'info, PassTier>,
+
+    #[account(
+        init_if_needed,
+        payer = viewer,
+        space = 8 + AccessPass::INIT_SPACE,
+        seeds = [b"access_pass", viewer.key().as_ref(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub access_pass: Account<'info, AccessPass>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Burn the tier's SOL-equivalent and extend the viewer's pass by the tier
+/// duration, starting from now or from the current expiry if still active.
+pub fn handler_burn_for_pass(ctx: Context<BurnForPass>, tier: u8) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let burn_sol_price = ctx.accounts.pass_tier.burn_sol_price;
+    let duration_seconds = ctx.accounts.pass_tier.duration_seconds;
+
+    let BurnSettlement {
+        tokens_burned,
+        platform_fee,
+        creator_fee,
+    } = settle_burn(
+        &ctx.accounts.global_config,
+        &mut ctx.accounts.bonding_curve,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.viewer_token_account,
+        &ctx.accounts.viewer,
+        &ctx.accounts.token_program,
+        burn_sol_price,
+    )?;
+
+    let pass = &mut ctx.accounts.access_pass;
+    let starts_at = pass.expires_at.max(timestamp);
+    pass.viewer = ctx.accounts.viewer.key();
+    pass.token_mint = ctx.accounts.token_mint.key();
+    pass.expires_at = starts_at
+        .checked_add(duration_seconds)
+        .ok_or(ErrorCode::MathOverflow)?;
+    pass.last_tier = tier;
+    pass.bump = ctx.bumps.access_pass;
+
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    emit!(AccessPassPurchased {
+        token_mint: bonding_curve.token_mint,
+        viewer: pass.viewer,
+        tier,
+        tokens_burned,
+        sol_value: burn_sol_price,
+        platform_fee,
+        creator_fee,
+        expires_at: pass.expires_at,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        real_sol_reserves: bonding_curve.real_sol_reserves,
+        real_token_reserves: bonding_curve.real_token_reserves,
+        tokens_sold: bonding_curve.tokens_sold,
+        token_total_supply: bonding_curve.token_total_supply,
+        timestamp,
+    });
+
+    Ok(())
+}
@@ -2,10 +2,12 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
 
 use crate::errors::ErrorCode;
+use crate::events::BurnedForAccess;
 use crate::math;
-use crate::state::{BondingCurve, GlobalConfig};
+use crate::state::{AccessReceipt, BondingCurve, GlobalConfig, PremiumContent, TradingStatus};
 
 #[derive(Accounts)]
+#[instruction(content_id: [u8; 32])]
 pub struct BurnAccess<'info> {
     #[account(mut)]
     pub viewer: Signer<'info>,
@@ -13,6 +15,7 @@ pub struct BurnAccess<'info> {
     #[account(
         seeds = [b"global_config"],
         bump = global_config.bump,
+        constraint = !global_config.paused @ ErrorCode::ProgramPaused,
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
@@ -20,6 +23,8 @@ pub struct BurnAccess<'info> {
         mut,
         seeds = [b"bonding_curve", token_mint.key().as_ref()],
         bump = bonding_curve.bump,
+        constraint = bonding_curve.trading_status != TradingStatus::Halted @ ErrorCode::TradingHalted,
+        constraint = bonding_curve.trading_status != TradingStatus::SellsOnly @ ErrorCode::SellsOnly,
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
 
@@ -33,45 +38,171 @@ pub struct BurnAccess<'info> {
     )]
     pub viewer_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: PremiumContent PDA for this content. May be uninitialized, in which
+    /// case the curve-wide burn price applies; deserialized in the handler otherwise.
+    #[account(
+        mut,
+        seeds = [b"premium_content", token_mint.key().as_ref(), content_id.as_ref()],
+        bump,
+    )]
+    pub premium_content: UncheckedAccount<'info>,
+
+    /// Created once per (viewer, mint, content); a repeat burn fails here
+    #[account(
+        init,
+        payer = viewer,
+        space = 8 + AccessReceipt::INIT_SPACE,
+        seeds = [
+            b"access_receipt",
+            viewer.key().as_ref(),
+            token_mint.key().as_ref(),
+            content_id.as_ref(),
+        ],
+        bump,
+    )]
+    pub access_receipt: Account<'info, AccessReceipt>,
+
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<BurnAccess>) -> Result<()> {
+pub fn handler(ctx: Context<BurnAccess>, content_id: [u8; 32]) -> Result<()> {
     let config = &ctx.accounts.global_config;
     let bonding_curve = &ctx.accounts.bonding_curve;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // Price from the registered PremiumContent if there is one, else the curve default
+    let burn_sol_price = resolve_burn_price(
+        &ctx.accounts.premium_content.to_account_info(),
+        bonding_curve,
+        timestamp,
+    )?;
+
+    let BurnSettlement {
+        tokens_burned,
+        platform_fee,
+        creator_fee,
+    } = settle_burn(
+        config,
+        &mut ctx.accounts.bonding_curve,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.viewer_token_account,
+        &ctx.accounts.viewer,
+        &ctx.accounts.token_program,
+        burn_sol_price,
+    )?;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    // Record the unlock on-chain
+    let receipt = &mut ctx.accounts.access_receipt;
+    receipt.viewer = ctx.accounts.viewer.key();
+    receipt.token_mint = bonding_curve.token_mint;
+    receipt.content_id = content_id;
+    receipt.tokens_burned = tokens_burned;
+    receipt.sol_value = burn_sol_price;
+    receipt.timestamp = timestamp;
+    receipt.payer = ctx.accounts.viewer.key();
+    receipt.bump = ctx.bumps.access_receipt;
+
+    emit!(BurnedForAccess {
+        token_mint: bonding_curve.token_mint,
+        viewer: ctx.accounts.viewer.key(),
+        content_id,
+        tokens_burned,
+        sol_value: burn_sol_price,
+        platform_fee,
+        creator_fee,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        real_sol_reserves: bonding_curve.real_sol_reserves,
+        real_token_reserves: bonding_curve.real_token_reserves,
+        tokens_sold: bonding_curve.tokens_sold,
+        token_total_supply: bonding_curve.token_total_supply,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+/// Price an unlock of one content item. Uses the PremiumContent PDA when it has
+/// been registered (enforcing expiry and unlock limit, and counting the unlock),
+/// otherwise the curve-wide `burn_sol_price`.
+pub(crate) fn resolve_burn_price(
+    premium_info: &AccountInfo,
+    bonding_curve: &BondingCurve,
+    timestamp: i64,
+) -> Result<u64> {
+    if premium_info.data_is_empty() {
+        return Ok(bonding_curve.burn_sol_price);
+    }
+
+    require_keys_eq!(*premium_info.owner, crate::ID, ErrorCode::Unauthorized);
+    let mut content = {
+        let data = premium_info.try_borrow_data()?;
+        PremiumContent::try_deserialize(&mut &data[..])?
+    };
+    require!(
+        content.expires_at == 0 || timestamp <= content.expires_at,
+        ErrorCode::ContentExpired
+    );
+    require!(
+        content.max_unlocks == 0 || content.unlock_count < content.max_unlocks,
+        ErrorCode::ContentUnlockLimitReached
+    );
+    content.unlock_count = content
+        .unlock_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    content.try_serialize(&mut &mut premium_info.try_borrow_mut_data()?[..])?;
 
+    Ok(content.burn_sol_price)
+}
+
+/// Outcome of a SOL-denominated burn against a bonding curve.
+pub(crate) struct BurnSettlement {
+    pub tokens_burned: u64,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+}
+
+/// Burn the viewer's tokens worth `burn_sol_price` and move the fee out of the
+/// curve reserves into the accrual fields. Shared by every burn-for-access
+/// flavour so pricing and accounting stay identical.
+pub(crate) fn settle_burn<'info>(
+    config: &GlobalConfig,
+    bonding_curve: &mut Account<'info, BondingCurve>,
+    token_mint: &Account<'info, Mint>,
+    viewer_token_account: &Account<'info, TokenAccount>,
+    viewer: &Signer<'info>,
+    token_program: &Program<'info, Token>,
+    burn_sol_price: u64,
+) -> Result<BurnSettlement> {
     // Burn must be enabled (burn_sol_price > 0)
-    require!(bonding_curve.burn_sol_price > 0, ErrorCode::BurnDisabled);
+    require!(burn_sol_price > 0, ErrorCode::BurnDisabled);
 
     // Calculate how many tokens to burn based on SOL-denominated price
-    let tokens_to_burn = math::calculate_tokens_for_sol_value(
-        bonding_curve.virtual_sol_reserves,
-        bonding_curve.virtual_token_reserves,
-        bonding_curve.burn_sol_price,
-    )?;
+    let tokens_to_burn =
+        math::curve_tokens_for_sol_value(&bonding_curve.curve_params(), burn_sol_price)?;
 
     // Check viewer has enough tokens
     require!(
-        ctx.accounts.viewer_token_account.amount >= tokens_to_burn,
+        viewer_token_account.amount >= tokens_to_burn,
         ErrorCode::InsufficientTokens
     );
 
     // Calculate fees from the SOL equivalent (burn_sol_price IS the SOL value)
-    let sol_equivalent = bonding_curve.burn_sol_price;
-    let total_fee = math::calculate_fee(sol_equivalent, config.fee_bps)?;
-    let platform_fee = total_fee / 2;
-    let creator_fee = total_fee
-        .checked_sub(platform_fee)
-        .ok_or(ErrorCode::MathOverflow)?;
+    let total_fee = math::calculate_fee(burn_sol_price, config.fee_bps)?;
+    let (platform_fee, creator_fee) =
+        math::split_fee(total_fee, config.platform_fee_bps, config.creator_fee_bps)?;
 
     // Burn tokens from viewer's account (viewer signs)
     token::burn(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
+            token_program.to_account_info(),
             Burn {
-                mint: ctx.accounts.token_mint.to_account_info(),
-                from: ctx.accounts.viewer_token_account.to_account_info(),
-                authority: ctx.accounts.viewer.to_account_info(),
+                mint: token_mint.to_account_info(),
+                from: viewer_token_account.to_account_info(),
+                authority: viewer.to_account_info(),
             },
         ),
         tokens_to_burn,
@@ -81,8 +212,6 @@ pub fn handler(ctx: Context<BurnAccess>) -> Result<()> {
     // Fees extracted from bonding_curve PDA reserves into accrual fields.
 
     // Update bonding curve state
-    let bonding_curve = &mut ctx.accounts.bonding_curve;
-
     // Fewer real tokens exist (burned)
     bonding_curve.real_token_reserves = bonding_curve
         .real_token_reserves
@@ -120,5 +249,9 @@ pub fn handler(ctx: Context<BurnAccess>) -> Result<()> {
         .checked_add(creator_fee)
         .ok_or(ErrorCode::MathOverflow)?;
 
-    Ok(())
+    Ok(BurnSettlement {
+        tokens_burned: tokens_to_burn,
+        platform_fee,
+        creator_fee,
+    })
 }
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::events::AccessGifted;
+use crate::instructions::burn_access::{resolve_burn_price, settle_burn, BurnSettlement};
+use crate::state::{AccessReceipt, BondingCurve, GlobalConfig, TradingStatus};
+
+#[derive(Accounts)]
+#[instruction(beneficiary: Pubkey, content_id: [u8; 32])]
+pub struct BurnAccessGift<'info> {
+    /// Pays with their tokens and for the receipt account rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        constraint = !global_config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", token_mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.trading_status != TradingStatus::Halted @ ErrorCode::TradingHalted,
+        constraint = bonding_curve.trading_status != TradingStatus::SellsOnly @ ErrorCode::SellsOnly,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(mut)]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = payer,
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PremiumContent PDA for this content. May be uninitialized, in which
+    /// case the curve-wide burn price applies; deserialized in the handler otherwise.
+    #[account(
+        mut,
+        seeds = [b"premium_content", token_mint.key().as_ref(), content_id.as_ref()],
+        bump,
+    )]
+    pub premium_content: UncheckedAccount<'info>,
+
+    /// The beneficiary's own AccessReceipt, so a gift and a self-burn share one
+    /// record: a repeat gift, or a gift of content already unlocked, fails here
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AccessReceipt::INIT_SPACE,
+        seeds = [
+            b"access_receipt",
+            beneficiary.as_ref(),
+            token_mint.key().as_ref(),
+            content_id.as_ref(),
+        ],
+        bump,
+    )]
+    pub access_receipt: Account<'info, AccessReceipt>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Same pricing and accounting as burn_for_access, but the receipt is recorded
+/// for `beneficiary` rather than the signer.
+pub fn handler(
+    ctx: Context<BurnAccessGift>,
+    beneficiary: Pubkey,
+    content_id: [u8; 32],
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    let burn_sol_price = resolve_burn_price(
+        &ctx.accounts.premium_content.to_account_info(),
+        &ctx.accounts.bonding_curve,
+        timestamp,
+    )?;
+
+    let BurnSettlement {
+        tokens_burned,
+        platform_fee,
+        creator_fee,
+    } = settle_burn(
+        &ctx.accounts.global_config,
+        &mut ctx.accounts.bonding_curve,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.payer_token_account,
+        &ctx.accounts.payer,
+        &ctx.accounts.token_program,
+        burn_sol_price,
+    )?;
+
+    let receipt = &mut ctx.accounts.access_receipt;
+    receipt.viewer = beneficiary;
+    receipt.token_mint = ctx.accounts.token_mint.key();
+    receipt.content_id = content_id;
+    receipt.tokens_burned = tokens_burned;
+    receipt.sol_value = burn_sol_price;
+    receipt.timestamp = timestamp;
+    receipt.payer = ctx.accounts.payer.key();
+    receipt.bump = ctx.bumps.access_receipt;
+
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    emit!(AccessGifted {
+        token_mint: bonding_curve.token_mint,
+        payer: receipt.payer,
+        beneficiary,
+        content_id,
+        tokens_burned,
+        sol_value: burn_sol_price,
+        platform_fee,
+        creator_fee,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        real_sol_reserves: bonding_curve.real_sol_reserves,
+        real_token_reserves: bonding_curve.real_token_reserves,
+        tokens_sold: bonding_curve.tokens_sold,
+        token_total_supply: bonding_curve.token_total_supply,
+        timestamp,
+    });
+
+    Ok(())
+}
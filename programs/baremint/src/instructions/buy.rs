@@ -1,19 +1,29 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::errors::ErrorCode;
+use crate::events::{Trade, TradeSide};
+use crate::instructions::referral::pay_referral;
 use crate::math;
-use crate::state::{BondingCurve, GlobalConfig};
+use crate::state::{BondingCurve, GlobalConfig, LaunchBuys, Referrer, TradingStatus};
 
 #[derive(Accounts)]
 pub struct Buy<'info> {
+    /// Pays the SOL cost of the trade and owns the purchased tokens
     #[account(mut)]
     pub buyer: Signer<'info>,
 
+    /// Transaction sponsor; pays rent for any accounts the buy creates. May be
+    /// the same key as `buyer`.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     #[account(
         seeds = [b"global_config"],
         bump = global_config.bump,
+        constraint = !global_config.paused @ ErrorCode::ProgramPaused,
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
@@ -21,6 +31,8 @@ pub struct Buy<'info> {
         mut,
         seeds = [b"bonding_curve", token_mint.key().as_ref()],
         bump = bonding_curve.bump,
+        constraint = bonding_curve.trading_status != TradingStatus::Halted @ ErrorCode::TradingHalted,
+        constraint = bonding_curve.trading_status != TradingStatus::SellsOnly @ ErrorCode::SellsOnly,
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
 
@@ -35,106 +47,195 @@ pub struct Buy<'info> {
     )]
     pub curve_token_account: Account<'info, TokenAccount>,
 
+    /// Buyer's associated token account, created by `payer` on first purchase
     #[account(
-        mut,
-        token::mint = token_mint,
-        token::authority = buyer,
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = buyer,
     )]
     pub buyer_token_account: Account<'info, TokenAccount>,
 
+    /// Per-wallet launch window tally. Required only while the curve is inside
+    /// its launch window.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + LaunchBuys::INIT_SPACE,
+        seeds = [b"launch_buys", token_mint.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub launch_buys: Option<Account<'info, LaunchBuys>>,
+
+    /// Optional registered referrer credited with part of the platform fee
+    #[account(
+        mut,
+        constraint = referrer.authority != buyer.key() @ ErrorCode::SelfReferral,
+    )]
+    pub referrer: Option<Account<'info, Referrer>>,
+
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Buy>, sol_amount: u64, min_tokens_out: u64) -> Result<()> {
+pub fn handler(
+    ctx: Context<Buy>,
+    sol_amount: u64,
+    min_tokens_out: u64,
+    deadline: Option<i64>,
+) -> Result<()> {
     let config = &ctx.accounts.global_config;
     let bonding_curve = &ctx.accounts.bonding_curve;
 
     // Calculate fees
     let total_fee = math::calculate_fee(sol_amount, config.fee_bps)?;
-    let platform_fee = total_fee / 2;
-    let creator_fee = total_fee
-        .checked_sub(platform_fee)
-        .ok_or(ErrorCode::MathOverflow)?;
+    let (platform_fee, creator_fee) =
+        math::split_fee(total_fee, config.platform_fee_bps, config.creator_fee_bps)?;
 
     // SOL going into curve (after fee deduction)
     let sol_into_curve = sol_amount
         .checked_sub(total_fee)
         .ok_or(ErrorCode::MathOverflow)?;
 
-    // Calculate tokens out from constant product curve
-    let tokens_out = math::calculate_buy_tokens(
-        bonding_curve.virtual_sol_reserves,
-        bonding_curve.virtual_token_reserves,
-        sol_into_curve,
-    )?;
+    // Calculate tokens out along the curve's shape
+    let tokens_out = math::curve_buy_tokens(&bonding_curve.curve_params(), sol_into_curve)?;
 
     // Slippage check
     require!(tokens_out >= min_tokens_out, ErrorCode::SlippageExceeded);
 
-    // Check sufficient real token reserves
-    require!(
-        bonding_curve.real_token_reserves >= tokens_out,
-        ErrorCode::InsufficientReserves
-    );
-
-    // Transfer ALL sol_amount from buyer to bonding_curve PDA
-    // (reserves + fees all held in the same PDA)
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.buyer.to_account_info(),
-                to: ctx.accounts.bonding_curve.to_account_info(),
-            },
-        ),
+    ctx.accounts.execute(
+        &ctx.bumps,
         sol_amount,
-    )?;
-
-    // Transfer tokens from curve to buyer
-    let token_mint_key = ctx.accounts.token_mint.key();
-    let bump = bonding_curve.bump;
-    let signer_seeds: &[&[&[u8]]] = &[&[b"bonding_curve", token_mint_key.as_ref(), &[bump]]];
-
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.curve_token_account.to_account_info(),
-                to: ctx.accounts.buyer_token_account.to_account_info(),
-                authority: ctx.accounts.bonding_curve.to_account_info(),
-            },
-            signer_seeds,
-        ),
+        sol_into_curve,
         tokens_out,
-    )?;
-
-    // Update bonding curve state
-    let bonding_curve = &mut ctx.accounts.bonding_curve;
-    bonding_curve.virtual_sol_reserves = bonding_curve
-        .virtual_sol_reserves
-        .checked_add(sol_into_curve)
-        .ok_or(ErrorCode::MathOverflow)?;
-    bonding_curve.virtual_token_reserves = bonding_curve
-        .virtual_token_reserves
-        .checked_sub(tokens_out)
-        .ok_or(ErrorCode::MathOverflow)?;
-    bonding_curve.real_sol_reserves = bonding_curve
-        .real_sol_reserves
-        .checked_add(sol_into_curve)
-        .ok_or(ErrorCode::MathOverflow)?;
-    bonding_curve.real_token_reserves = bonding_curve
-        .real_token_reserves
-        .checked_sub(tokens_out)
-        .ok_or(ErrorCode::MathOverflow)?;
-    bonding_curve.platform_fees_accrued = bonding_curve
-        .platform_fees_accrued
-        .checked_add(platform_fee)
-        .ok_or(ErrorCode::MathOverflow)?;
-    bonding_curve.creator_fees_accrued = bonding_curve
-        .creator_fees_accrued
-        .checked_add(creator_fee)
-        .ok_or(ErrorCode::MathOverflow)?;
+        platform_fee,
+        creator_fee,
+        deadline,
+    )
+}
 
-    Ok(())
+impl<'info> Buy<'info> {
+    /// Settle a quoted buy: move SOL and tokens, update curve state and emit the
+    /// Trade event. Shared by buy and buy_exact_tokens.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn execute(
+        &mut self,
+        bumps: &BuyBumps,
+        sol_amount: u64,
+        sol_into_curve: u64,
+        tokens_out: u64,
+        platform_fee: u64,
+        creator_fee: u64,
+        deadline: Option<i64>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // Reject stale transactions (e.g. retried long after the quote was shown)
+        if let Some(deadline) = deadline {
+            require!(now <= deadline, ErrorCode::DeadlineExceeded);
+        }
+
+        // Anti-sniping limits while the launch window is open
+        if now < self.bonding_curve.launch_window_ends_at {
+            let max_sol_per_tx = self.bonding_curve.max_sol_per_tx;
+            require!(
+                max_sol_per_tx == 0 || sol_amount <= max_sol_per_tx,
+                ErrorCode::LaunchSolPerTxExceeded
+            );
+
+            let launch_buys = self
+                .launch_buys
+                .as_mut()
+                .ok_or(ErrorCode::LaunchBuysAccountRequired)?;
+            launch_buys.buyer = self.buyer.key();
+            launch_buys.token_mint = self.token_mint.key();
+            launch_buys.bump = bumps
+                .launch_buys
+                .ok_or(ErrorCode::LaunchBuysAccountRequired)?;
+            launch_buys.tokens_bought = launch_buys
+                .tokens_bought
+                .checked_add(tokens_out)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            let max_tokens_per_wallet = self.bonding_curve.max_tokens_per_wallet;
+            require!(
+                max_tokens_per_wallet == 0 || launch_buys.tokens_bought <= max_tokens_per_wallet,
+                ErrorCode::LaunchWalletCapExceeded
+            );
+        }
+
+        // Check sufficient real token reserves
+        require!(
+            self.bonding_curve.real_token_reserves >= tokens_out,
+            ErrorCode::InsufficientReserves
+        );
+
+        // Transfer ALL sol_amount from buyer to bonding_curve PDA
+        // (reserves + fees all held in the same PDA)
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.buyer.to_account_info(),
+                    to: self.bonding_curve.to_account_info(),
+                },
+            ),
+            sol_amount,
+        )?;
+
+        // Transfer tokens from curve to buyer
+        let token_mint_key = self.token_mint.key();
+        let bump = self.bonding_curve.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"bonding_curve", token_mint_key.as_ref(), &[bump]]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.curve_token_account.to_account_info(),
+                    to: self.buyer_token_account.to_account_info(),
+                    authority: self.bonding_curve.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            tokens_out,
+        )?;
+
+        // Referral share comes out of the platform fee already held by the curve
+        let (platform_fee, referral_fee) = pay_referral(
+            &self.global_config,
+            &self.bonding_curve.to_account_info(),
+            self.referrer.as_mut(),
+            platform_fee,
+        )?;
+        let referrer = self
+            .referrer
+            .as_ref()
+            .map_or(Pubkey::default(), |r| r.authority);
+
+        // Update bonding curve state
+        let bonding_curve = &mut self.bonding_curve;
+        bonding_curve.apply_buy(sol_into_curve, tokens_out, platform_fee, creator_fee)?;
+
+        emit!(Trade {
+            token_mint: bonding_curve.token_mint,
+            trader: self.buyer.key(),
+            side: TradeSide::Buy,
+            sol_amount,
+            token_amount: tokens_out,
+            platform_fee,
+            creator_fee,
+            referrer,
+            referral_fee,
+            virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+            virtual_token_reserves: bonding_curve.virtual_token_reserves,
+            real_sol_reserves: bonding_curve.real_sol_reserves,
+            real_token_reserves: bonding_curve.real_token_reserves,
+            tokens_sold: bonding_curve.tokens_sold,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
 }
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::buy::Buy;
+use crate::math;
+
+/// Buy exactly `token_amount` tokens, paying at most `max_sol_in` lamports
+/// (fees included). Uses the same accounts as buy.
+pub fn handler(
+    ctx: Context<Buy>,
+    token_amount: u64,
+    max_sol_in: u64,
+    deadline: Option<i64>,
+) -> Result<()> {
+    let config = &ctx.accounts.global_config;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    // SOL that must reach the curve for exactly token_amount (rounded up)
+    let min_sol_into_curve =
+        math::curve_buy_sol_for_tokens(&bonding_curve.curve_params(), token_amount)?;
+
+    // Gross up for the fee that buy deducts from the input
+    let sol_amount = math::calculate_gross_for_net(min_sol_into_curve, config.fee_bps)?;
+
+    // Slippage check on total SOL spent
+    require!(sol_amount <= max_sol_in, ErrorCode::SlippageExceeded);
+
+    // Recompute the fee exactly as buy does; any rounding surplus stays in the curve
+    let total_fee = math::calculate_fee(sol_amount, config.fee_bps)?;
+    let (platform_fee, creator_fee) =
+        math::split_fee(total_fee, config.platform_fee_bps, config.creator_fee_bps)?;
+    let sol_into_curve = sol_amount
+        .checked_sub(total_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    ctx.accounts.execute(
+        &ctx.bumps,
+        sol_amount,
+        sol_into_curve,
+        token_amount,
+        platform_fee,
+        creator_fee,
+        deadline,
+    )
+}
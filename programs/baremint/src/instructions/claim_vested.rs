@@ -2,24 +2,19 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::errors::ErrorCode;
-use crate::state::{GlobalConfig, VestingAccount};
+use crate::events::VestingClaimed;
+use crate::state::VestingAccount;
 
 #[derive(Accounts)]
 pub struct ClaimVested<'info> {
+    /// Creator or any other wallet holding a share of the allocation
     #[account(mut)]
-    pub creator: Signer<'info>,
-
-    #[account(
-        seeds = [b"global_config"],
-        bump = global_config.bump,
-    )]
-    pub global_config: Account<'info, GlobalConfig>,
+    pub beneficiary: Signer<'info>,
 
     #[account(
         mut,
         seeds = [b"vesting", token_mint.key().as_ref()],
         bump = vesting_account.bump,
-        constraint = creator.key() == vesting_account.creator @ ErrorCode::Unauthorized,
     )]
     pub vesting_account: Account<'info, VestingAccount>,
 
@@ -37,27 +32,32 @@ pub struct ClaimVested<'info> {
     #[account(
         mut,
         token::mint = token_mint,
-        token::authority = creator,
+        token::authority = beneficiary,
     )]
-    pub creator_token_account: Account<'info, TokenAccount>,
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
 }
 
 pub fn handler(ctx: Context<ClaimVested>) -> Result<()> {
-    let config = &ctx.accounts.global_config;
     let vesting = &ctx.accounts.vesting_account;
 
     // Check vesting has not been revoked
     require!(!vesting.is_revoked, ErrorCode::VestingRevoked);
 
+    let index = vesting
+        .beneficiaries
+        .iter()
+        .position(|b| b.share.wallet == ctx.accounts.beneficiary.key())
+        .ok_or(ErrorCode::NotABeneficiary)?;
+
     // Get current time
     let current_time = Clock::get()?.unix_timestamp;
 
-    // Check cliff has been reached
+    // Check cliff has been reached (schedule was snapshotted at launch)
     let cliff_end = vesting
         .start_timestamp
-        .checked_add(config.vesting_cliff_seconds)
+        .checked_add(vesting.cliff_seconds)
         .ok_or(ErrorCode::MathOverflow)?;
     require!(current_time >= cliff_end, ErrorCode::VestingCliffNotReached);
 
@@ -66,29 +66,31 @@ pub fn handler(ctx: Context<ClaimVested>) -> Result<()> {
         current_time
             .checked_sub(cliff_end)
             .ok_or(ErrorCode::MathOverflow)?,
-        config.vesting_duration_seconds,
+        vesting.duration_seconds,
     );
 
-    // Snap to weekly windows
-    let weeks_elapsed = elapsed_since_cliff / config.vesting_claim_interval_seconds;
-    let snapped_elapsed = weeks_elapsed
-        .checked_mul(config.vesting_claim_interval_seconds)
+    // Snap to claim interval windows
+    let intervals_elapsed = elapsed_since_cliff / vesting.claim_interval_seconds;
+    let snapped_elapsed = intervals_elapsed
+        .checked_mul(vesting.claim_interval_seconds)
         .ok_or(ErrorCode::MathOverflow)?;
 
-    // Linear vesting: total_vested = total_allocation * snapped_elapsed / vesting_duration_seconds
+    // Linear vesting: total_vested = total_allocation * snapped_elapsed / duration_seconds
     let total_vested = (vesting.total_allocation as u128)
         .checked_mul(snapped_elapsed as u128)
         .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(config.vesting_duration_seconds as u128)
+        .checked_div(vesting.duration_seconds as u128)
         .ok_or(ErrorCode::MathOverflow)? as u64;
 
-    let claimable = total_vested
-        .checked_sub(vesting.claimed_amount)
+    // This beneficiary's pro-rata part of everything vested so far
+    let entitled = vesting.vested_for(index, total_vested)?;
+    let claimable = entitled
+        .checked_sub(vesting.beneficiaries[index].claimed_amount)
         .ok_or(ErrorCode::MathOverflow)?;
 
     require!(claimable > 0, ErrorCode::VestingFullyClaimed);
 
-    // Transfer claimable tokens from vesting_token_account to creator_token_account
+    // Transfer claimable tokens from vesting_token_account to beneficiary_token_account
     let token_mint_key = ctx.accounts.token_mint.key();
     let bump = vesting.bump;
     let signer_seeds: &[&[&[u8]]] = &[&[b"vesting", token_mint_key.as_ref(), &[bump]]];
@@ -98,7 +100,7 @@ pub fn handler(ctx: Context<ClaimVested>) -> Result<()> {
             ctx.accounts.token_program.to_account_info(),
             Transfer {
                 from: ctx.accounts.vesting_token_account.to_account_info(),
-                to: ctx.accounts.creator_token_account.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
                 authority: ctx.accounts.vesting_account.to_account_info(),
             },
             signer_seeds,
@@ -106,12 +108,28 @@ pub fn handler(ctx: Context<ClaimVested>) -> Result<()> {
         claimable,
     )?;
 
-    // Update claimed amount
+    // Update claimed amounts
     let vesting = &mut ctx.accounts.vesting_account;
+    let beneficiary = &mut vesting.beneficiaries[index];
+    beneficiary.claimed_amount = beneficiary
+        .claimed_amount
+        .checked_add(claimable)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let beneficiary_claimed_amount = beneficiary.claimed_amount;
     vesting.claimed_amount = vesting
         .claimed_amount
         .checked_add(claimable)
         .ok_or(ErrorCode::MathOverflow)?;
 
+    emit!(VestingClaimed {
+        token_mint: vesting.token_mint,
+        creator: vesting.creator,
+        beneficiary: ctx.accounts.beneficiary.key(),
+        amount: claimable,
+        beneficiary_claimed_amount,
+        claimed_amount: vesting.claimed_amount,
+        timestamp: current_time,
+    });
+
     Ok(())
 }
@@ -1,23 +1,73 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::mpl_token_metadata::types::DataV2;
+use anchor_spl::metadata::mpl_token_metadata::{
+    MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+};
+use anchor_spl::metadata::{self, CreateMetadataAccountsV3, Metadata};
 use anchor_spl::token::{self, Mint, MintTo, SetAuthority, Token, TokenAccount, Transfer};
 
 use crate::errors::ErrorCode;
-use crate::state::{BondingCurve, CreatorProfile, GlobalConfig, VestingAccount};
+use crate::events::{TokenCreated, Trade, TradeSide};
+use crate::math;
+use crate::state::{
+    BondingCurve, CreatorProfile, CurveKind, GlobalConfig, TradingStatus, VestingAccount,
+    VestingBeneficiary, WalletShare, MAX_VESTING_BENEFICIARIES,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateTokenParams {
+    /// SOL-denominated burn cost (0 = burns disabled)
+    pub burn_sol_price: u64,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub is_mutable: bool,
+    /// SOL the creator spends buying at the pristine curve price (0 = no launch buy)
+    pub initial_buy_sol: u64,
+    /// Slippage floor for the launch buy
+    pub min_tokens_out: u64,
+    /// Price curve shape, must be allowed by the platform
+    pub curve_kind: CurveKind,
+    /// Tokens to mint in base units, within the platform's supply bounds
+    pub total_supply: u64,
+    /// Mint decimals, within the platform's decimal bounds
+    pub decimals: u8,
+    /// Creator allocation in basis points (None = platform default)
+    pub creator_allocation_bps: Option<u16>,
+    /// Vesting cliff in seconds (None = platform default)
+    pub vesting_cliff_seconds: Option<i64>,
+    /// Vesting duration after the cliff in seconds (None = platform default)
+    pub vesting_duration_seconds: Option<i64>,
+    /// Vesting claim interval in seconds (None = platform default)
+    pub vesting_claim_interval_seconds: Option<i64>,
+    /// Split of the vesting allocation (empty = all to the creator)
+    pub beneficiaries: Vec<WalletShare>,
+}
 
 #[derive(Accounts)]
+#[instruction(params: CreateTokenParams)]
 pub struct CreateToken<'info> {
+    /// Launching creator; pays the SOL for an optional launch buy
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    /// Pays rent for every account created here. May be the creator itself or a
+    /// platform wallet sponsoring the launch.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     #[account(
         seeds = [b"global_config"],
         bump = global_config.bump,
+        constraint = !global_config.paused @ ErrorCode::ProgramPaused,
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
     #[account(
         init_if_needed,
-        payer = creator,
+        payer = payer,
         seeds = [b"creator_profile", creator.key().as_ref()],
         bump,
         space = 8 + CreatorProfile::INIT_SPACE,
@@ -26,15 +76,15 @@ pub struct CreateToken<'info> {
 
     #[account(
         init,
-        payer = creator,
-        mint::decimals = 6,
+        payer = payer,
+        mint::decimals = params.decimals,
         mint::authority = bonding_curve,
     )]
     pub token_mint: Account<'info, Mint>,
 
     #[account(
         init,
-        payer = creator,
+        payer = payer,
         seeds = [b"bonding_curve", token_mint.key().as_ref()],
         bump,
         space = 8 + BondingCurve::INIT_SPACE,
@@ -43,17 +93,17 @@ pub struct CreateToken<'info> {
 
     #[account(
         init,
-        payer = creator,
+        payer = payer,
         token::mint = token_mint,
         token::authority = bonding_curve,
         seeds = [b"curve_tokens", token_mint.key().as_ref()],
         bump,
     )]
-    pub curve_token_account: Account<'info, TokenAccount>,
+    pub curve_token_account: Box<Account<'info, TokenAccount>>,
 
     #[account(
         init,
-        payer = creator,
+        payer = payer,
         seeds = [b"vesting", token_mint.key().as_ref()],
         bump,
         space = 8 + VestingAccount::INIT_SPACE,
@@ -62,24 +112,105 @@ pub struct CreateToken<'info> {
 
     #[account(
         init,
-        payer = creator,
+        payer = payer,
         token::mint = token_mint,
         token::authority = vesting_account,
         seeds = [b"vesting_tokens", token_mint.key().as_ref()],
         bump,
     )]
-    pub vesting_token_account: Account<'info, TokenAccount>,
+    pub vesting_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Creator's associated token account, receives the launch buy. Required
+    /// when `initial_buy_sol` is non-zero; omit it otherwise so no account is
+    /// created.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Metaplex metadata PDA for token_mint, created by the CPI below
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), token_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Platform authority, recorded as the metadata update authority
+    #[account(address = global_config.authority @ ErrorCode::Unauthorized)]
+    pub metadata_update_authority: UncheckedAccount<'info>,
 
+    pub token_metadata_program: Program<'info, Metadata>,
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<CreateToken>, burn_sol_price: u64) -> Result<()> {
+pub fn handler(ctx: Context<CreateToken>, params: CreateTokenParams) -> Result<()> {
+    let CreateTokenParams {
+        burn_sol_price,
+        name,
+        symbol,
+        uri,
+        is_mutable,
+        initial_buy_sol,
+        min_tokens_out,
+        curve_kind,
+        total_supply,
+        decimals,
+        creator_allocation_bps,
+        vesting_cliff_seconds,
+        vesting_duration_seconds,
+        vesting_claim_interval_seconds,
+        beneficiaries,
+    } = params;
+
+    // Validate metadata up front for a clearer error than the Metaplex CPI gives
+    require!(
+        name.len() <= MAX_NAME_LENGTH
+            && symbol.len() <= MAX_SYMBOL_LENGTH
+            && uri.len() <= MAX_URI_LENGTH,
+        ErrorCode::InvalidTokenMetadata
+    );
+
     let clock = Clock::get()?;
     let config = &ctx.accounts.global_config;
+    config.validate_burn_sol_price(burn_sol_price)?;
+    require!(
+        config.allowed_curve_kinds & curve_kind.mask() != 0,
+        ErrorCode::CurveKindNotAllowed
+    );
+    require!(
+        total_supply >= config.min_total_supply
+            && total_supply <= config.max_total_supply
+            && decimals >= config.min_token_decimals
+            && decimals <= config.max_token_decimals,
+        ErrorCode::InvalidTokenSupply
+    );
     let creator_profile = &ctx.accounts.creator_profile;
 
+    // Vesting schedule is snapshotted per launch so later config changes
+    // cannot alter it
+    let creator_allocation_bps = creator_allocation_bps.unwrap_or(config.creator_allocation_bps);
+    let vesting_cliff_seconds = vesting_cliff_seconds.unwrap_or(config.vesting_cliff_seconds);
+    let vesting_duration_seconds =
+        vesting_duration_seconds.unwrap_or(config.vesting_duration_seconds);
+    let vesting_claim_interval_seconds =
+        vesting_claim_interval_seconds.unwrap_or(config.vesting_claim_interval_seconds);
+    config.validate_vesting_schedule(
+        creator_allocation_bps,
+        vesting_cliff_seconds,
+        vesting_duration_seconds,
+        vesting_claim_interval_seconds,
+    )?;
+
+    let beneficiaries = build_beneficiaries(ctx.accounts.creator.key(), beneficiaries)?;
+
     // Check 90-day cooldown
     if creator_profile.last_token_launch_timestamp > 0 {
         let elapsed = clock
@@ -92,17 +223,32 @@ pub fn handler(ctx: Context<CreateToken>, burn_sol_price: u64) -> Result<()> {
         );
     }
 
-    // Calculate supply distribution
-    let total_supply: u64 = 1_000_000_000_000_000; // 1B with 6 decimals
-    let creator_allocation_bps = config.creator_allocation_bps as u64;
-    let vesting_amount = total_supply
-        .checked_mul(creator_allocation_bps)
+    // Calculate supply distribution (u128: max supply times bps exceeds u64)
+    let vesting_amount = (total_supply as u128)
+        .checked_mul(creator_allocation_bps as u128)
         .ok_or(ErrorCode::MathOverflow)?
         / 10_000;
+    let vesting_amount =
+        u64::try_from(vesting_amount).map_err(|_| error!(ErrorCode::MathOverflow))?;
     let curve_amount = total_supply
         .checked_sub(vesting_amount)
         .ok_or(ErrorCode::MathOverflow)?;
 
+    // Virtual reserves are configured for the reference supply
+    let virtual_token_reserves =
+        math::scale_virtual_token_reserves(config.initial_virtual_token_reserves, total_supply)?;
+
+    // Shaped curves span the same price range the constant product curve would
+    let (curve_start_price, curve_end_price) = if curve_kind == CurveKind::ConstantProduct {
+        (0, 0)
+    } else {
+        math::calculate_shaped_price_range(
+            config.initial_virtual_sol_reserves,
+            virtual_token_reserves,
+            curve_amount,
+        )?
+    };
+
     // Bonding curve PDA signer seeds
     let token_mint_key = ctx.accounts.token_mint.key();
     let bump = ctx.bumps.bonding_curve;
@@ -136,6 +282,37 @@ pub fn handler(ctx: Context<CreateToken>, burn_sol_price: u64) -> Result<()> {
         vesting_amount,
     )?;
 
+    // Create Metaplex metadata while bonding_curve is still the mint authority.
+    // The platform authority keeps update rights so moderation can correct
+    // abusive metadata; creators who want it frozen pass is_mutable = false.
+    metadata::create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                mint_authority: ctx.accounts.bonding_curve.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                update_authority: ctx.accounts.metadata_update_authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        DataV2 {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        is_mutable,
+        false,
+        None,
+    )?;
+
     // Revoke mint authority -- makes token supply permanently fixed
     token::set_authority(
         CpiContext::new_with_signer(
@@ -154,14 +331,31 @@ pub fn handler(ctx: Context<CreateToken>, burn_sol_price: u64) -> Result<()> {
     let bonding_curve = &mut ctx.accounts.bonding_curve;
     bonding_curve.token_mint = ctx.accounts.token_mint.key();
     bonding_curve.creator = ctx.accounts.creator.key();
-    bonding_curve.virtual_token_reserves = config.initial_virtual_token_reserves;
+    bonding_curve.virtual_token_reserves = virtual_token_reserves;
     bonding_curve.virtual_sol_reserves = config.initial_virtual_sol_reserves;
     bonding_curve.real_token_reserves = curve_amount;
     bonding_curve.real_sol_reserves = 0;
     bonding_curve.token_total_supply = total_supply;
     bonding_curve.burn_sol_price = burn_sol_price;
+    bonding_curve.burn_price_updated_at = 0;
     bonding_curve.platform_fees_accrued = 0;
     bonding_curve.creator_fees_accrued = 0;
+    bonding_curve.trading_status = TradingStatus::Active;
+    bonding_curve.launch_window_ends_at = if config.launch_window_seconds > 0 {
+        clock
+            .unix_timestamp
+            .checked_add(config.launch_window_seconds)
+            .ok_or(ErrorCode::MathOverflow)?
+    } else {
+        0
+    };
+    bonding_curve.max_tokens_per_wallet = config.launch_max_tokens_per_wallet;
+    bonding_curve.max_sol_per_tx = config.launch_max_sol_per_tx;
+    bonding_curve.curve_kind = curve_kind;
+    bonding_curve.tokens_sold = 0;
+    bonding_curve.curve_supply = curve_amount;
+    bonding_curve.curve_start_price = curve_start_price;
+    bonding_curve.curve_end_price = curve_end_price;
     bonding_curve.bump = bump;
 
     // Initialize VestingAccount
@@ -172,6 +366,11 @@ pub fn handler(ctx: Context<CreateToken>, burn_sol_price: u64) -> Result<()> {
     vesting.claimed_amount = 0;
     vesting.start_timestamp = clock.unix_timestamp;
     vesting.is_revoked = false;
+    vesting.allocation_bps = creator_allocation_bps;
+    vesting.cliff_seconds = vesting_cliff_seconds;
+    vesting.duration_seconds = vesting_duration_seconds;
+    vesting.claim_interval_seconds = vesting_claim_interval_seconds;
+    vesting.beneficiaries = beneficiaries;
     vesting.bump = ctx.bumps.vesting_account;
 
     // Update CreatorProfile
@@ -184,5 +383,138 @@ pub fn handler(ctx: Context<CreateToken>, burn_sol_price: u64) -> Result<()> {
         .ok_or(ErrorCode::MathOverflow)?;
     profile.bump = ctx.bumps.creator_profile;
 
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    emit!(TokenCreated {
+        token_mint: bonding_curve.token_mint,
+        creator: bonding_curve.creator,
+        bonding_curve: bonding_curve.key(),
+        token_total_supply: bonding_curve.token_total_supply,
+        decimals,
+        real_token_reserves: bonding_curve.real_token_reserves,
+        vesting_amount,
+        vesting_cliff_seconds,
+        vesting_duration_seconds,
+        vesting_claim_interval_seconds,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        curve_kind: bonding_curve.curve_kind,
+        curve_supply: bonding_curve.curve_supply,
+        curve_start_price: bonding_curve.curve_start_price,
+        curve_end_price: bonding_curve.curve_end_price,
+        burn_sol_price: bonding_curve.burn_sol_price,
+        name,
+        symbol,
+        uri,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Optional creator launch buy, in the same instruction so no one can trade
+    // ahead of it
+    if initial_buy_sol > 0 {
+        let creator_token_account = ctx
+            .accounts
+            .creator_token_account
+            .as_ref()
+            .ok_or(ErrorCode::CreatorTokenAccountRequired)?;
+        let config = &ctx.accounts.global_config;
+        let total_fee = math::calculate_fee(initial_buy_sol, config.fee_bps)?;
+        let (platform_fee, creator_fee) =
+            math::split_fee(total_fee, config.platform_fee_bps, config.creator_fee_bps)?;
+        let sol_into_curve = initial_buy_sol
+            .checked_sub(total_fee)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let tokens_out = math::curve_buy_tokens(&bonding_curve.curve_params(), sol_into_curve)?;
+        require!(tokens_out >= min_tokens_out, ErrorCode::SlippageExceeded);
+
+        // Platform cap on how much supply the creator can take at launch
+        let max_tokens = (total_supply as u128)
+            .checked_mul(config.max_initial_buy_bps as u128)
+            .ok_or(ErrorCode::MathOverflow)?
+            / 10_000;
+        require!(
+            (tokens_out as u128) <= max_tokens,
+            ErrorCode::InitialBuyExceedsCap
+        );
+        require!(
+            bonding_curve.real_token_reserves >= tokens_out,
+            ErrorCode::InsufficientReserves
+        );
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.bonding_curve.to_account_info(),
+                },
+            ),
+            initial_buy_sol,
+        )?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.curve_token_account.to_account_info(),
+                    to: creator_token_account.to_account_info(),
+                    authority: ctx.accounts.bonding_curve.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            tokens_out,
+        )?;
+
+        let bonding_curve = &mut ctx.accounts.bonding_curve;
+        bonding_curve.apply_buy(sol_into_curve, tokens_out, platform_fee, creator_fee)?;
+
+        emit!(Trade {
+            token_mint: bonding_curve.token_mint,
+            trader: bonding_curve.creator,
+            side: TradeSide::Buy,
+            sol_amount: initial_buy_sol,
+            token_amount: tokens_out,
+            platform_fee,
+            creator_fee,
+            referrer: Pubkey::default(),
+            referral_fee: 0,
+            virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+            virtual_token_reserves: bonding_curve.virtual_token_reserves,
+            real_sol_reserves: bonding_curve.real_sol_reserves,
+            real_token_reserves: bonding_curve.real_token_reserves,
+            tokens_sold: bonding_curve.tokens_sold,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     Ok(())
 }
+
+/// Validate the requested vesting split of at most MAX_VESTING_BENEFICIARIES
+/// wallets. No split means the creator keeps the whole allocation.
+fn build_beneficiaries(
+    creator: Pubkey,
+    shares: Vec<WalletShare>,
+) -> Result<Vec<VestingBeneficiary>> {
+    if shares.is_empty() {
+        return Ok(vec![VestingBeneficiary {
+            share: WalletShare {
+                wallet: creator,
+                share_bps: 10_000,
+            },
+            claimed_amount: 0,
+        }]);
+    }
+
+    require!(
+        WalletShare::is_valid_split(&shares, MAX_VESTING_BENEFICIARIES),
+        ErrorCode::InvalidBeneficiaries
+    );
+
+    Ok(shares
+        .into_iter()
+        .map(|share| VestingBeneficiary {
+            share,
+            claimed_amount: 0,
+        })
+        .collect())
+}
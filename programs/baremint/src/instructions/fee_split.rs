@@ -0,0 +1,234 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::{FeeKind, FeeSplitApplied, FeeSplitProposed, FeesWithdrawn};
+use crate::math;
+use crate::state::{BondingCurve, FeeSplit, GlobalConfig, WalletShare, MAX_FEE_SPLIT_RECIPIENTS};
+
+// --- Set Fee Split ---
+
+#[derive(Accounts)]
+pub struct SetFeeSplit<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [b"bonding_curve", token_mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        constraint = creator.key() == bonding_curve.creator @ ErrorCode::Unauthorized,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + FeeSplit::INIT_SPACE,
+        seeds = [b"fee_split", token_mint.key().as_ref()],
+        bump,
+    )]
+    pub fee_split: Account<'info, FeeSplit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the fee split, or propose a replacement. A new split takes effect
+/// immediately; changes to an existing one wait out the platform timelock so
+/// collaborators see them coming. A new proposal replaces any pending one.
+pub fn handler_set_fee_split(
+    ctx: Context<SetFeeSplit>,
+    recipients: Vec<WalletShare>,
+) -> Result<()> {
+    require!(
+        WalletShare::is_valid_split(&recipients, MAX_FEE_SPLIT_RECIPIENTS),
+        ErrorCode::InvalidFeeSplit
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let timelock = ctx.accounts.global_config.fee_split_timelock_seconds;
+    let fee_split = &mut ctx.accounts.fee_split;
+
+    let effective_at = if fee_split.recipients.is_empty() {
+        fee_split.token_mint = ctx.accounts.token_mint.key();
+        fee_split.recipients = recipients.clone();
+        fee_split.carried_fees = vec![0; recipients.len()];
+        fee_split.pending_recipients = Vec::new();
+        fee_split.pending_effective_at = 0;
+        fee_split.bump = ctx.bumps.fee_split;
+        now
+    } else {
+        let effective_at = now.checked_add(timelock).ok_or(ErrorCode::MathOverflow)?;
+        fee_split.pending_recipients = recipients.clone();
+        fee_split.pending_effective_at = effective_at;
+        effective_at
+    };
+
+    emit!(FeeSplitProposed {
+        token_mint: fee_split.token_mint,
+        recipients,
+        effective_at,
+    });
+
+    Ok(())
+}
+
+// --- Apply Fee Split ---
+
+#[derive(Accounts)]
+pub struct ApplyFeeSplit<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_split", fee_split.token_mint.as_ref()],
+        bump = fee_split.bump,
+    )]
+    pub fee_split: Account<'info, FeeSplit>,
+}
+
+/// Permissionless: swap in the pending split once its timelock has passed.
+/// Fees still carried for the old recipients stay accrued on the curve and are
+/// split under the new recipients.
+pub fn handler_apply_fee_split(ctx: Context<ApplyFeeSplit>) -> Result<()> {
+    let fee_split = &mut ctx.accounts.fee_split;
+    require!(
+        fee_split.pending_effective_at != 0,
+        ErrorCode::NoPendingFeeSplit
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= fee_split.pending_effective_at,
+        ErrorCode::FeeSplitTimelockActive
+    );
+
+    fee_split.recipients = std::mem::take(&mut fee_split.pending_recipients);
+    fee_split.carried_fees = vec![0; fee_split.recipients.len()];
+    fee_split.pending_effective_at = 0;
+
+    emit!(FeeSplitApplied {
+        token_mint: fee_split.token_mint,
+        recipients: fee_split.recipients.clone(),
+    });
+
+    Ok(())
+}
+
+// --- Distribute Creator Fees ---
+
+#[derive(Accounts)]
+pub struct DistributeCreatorFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", token_mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_split", token_mint.key().as_ref()],
+        bump = fee_split.bump,
+    )]
+    pub fee_split: Account<'info, FeeSplit>,
+    // remaining_accounts: one writable account per recipient, in split order
+}
+
+/// Permissionless: pay out all accrued creator fees according to the split.
+/// New fees are split pro rata (shares round down, the last recipient takes the
+/// remainder) and added to anything carried for each recipient. A payout that
+/// would leave its recipient below rent exemption is carried forward instead of
+/// failing the transaction, so one unfunded wallet cannot block the others.
+pub fn handler_distribute_creator_fees<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributeCreatorFees<'info>>,
+) -> Result<()> {
+    let amount = ctx.accounts.bonding_curve.creator_fees_accrued;
+
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let fee_split = &mut ctx.accounts.fee_split;
+    require!(
+        ctx.remaining_accounts.len() == fee_split.recipients.len(),
+        ErrorCode::FeeRecipientMismatch
+    );
+
+    // Ensure bonding_curve retains enough lamports for rent exemption
+    let bonding_curve_info = ctx.accounts.bonding_curve.to_account_info();
+    let rent = Rent::get()?;
+    let min_lamports = rent.minimum_balance(bonding_curve_info.data_len());
+    let current_lamports = bonding_curve_info.lamports();
+    require!(
+        current_lamports
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathOverflow)?
+            >= min_lamports,
+        ErrorCode::InsufficientReserves
+    );
+
+    // Only fees accrued since the last distribution are split; carried ones
+    // already belong to a recipient
+    let carried_total = fee_split
+        .carried_fees
+        .iter()
+        .try_fold(0u64, |total, &carried| total.checked_add(carried))
+        .ok_or(ErrorCode::MathOverflow)?;
+    let new_fees = amount
+        .checked_sub(carried_total)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let token_mint = ctx.accounts.token_mint.key();
+    let shares_bps: Vec<u16> = fee_split.recipients.iter().map(|r| r.share_bps).collect();
+    let parts = math::split_by_bps(new_fees, &shares_bps)?;
+    let mut still_carried: u64 = 0;
+    for (i, (recipient_info, part)) in ctx.remaining_accounts.iter().zip(parts).enumerate() {
+        let wallet = fee_split.recipients[i].wallet;
+        require!(
+            recipient_info.key() == wallet && recipient_info.is_writable,
+            ErrorCode::FeeRecipientMismatch
+        );
+
+        let owed = part
+            .checked_add(fee_split.carried_fees[i])
+            .ok_or(ErrorCode::MathOverflow)?;
+        if owed == 0 {
+            continue;
+        }
+
+        let balance_after = recipient_info
+            .lamports()
+            .checked_add(owed)
+            .ok_or(ErrorCode::MathOverflow)?;
+        if balance_after < rent.minimum_balance(recipient_info.data_len()) {
+            fee_split.carried_fees[i] = owed;
+            still_carried = still_carried
+                .checked_add(owed)
+                .ok_or(ErrorCode::MathOverflow)?;
+            continue;
+        }
+
+        **bonding_curve_info.try_borrow_mut_lamports()? -= owed;
+        **recipient_info.try_borrow_mut_lamports()? += owed;
+        fee_split.carried_fees[i] = 0;
+
+        emit!(FeesWithdrawn {
+            token_mint,
+            recipient: wallet,
+            kind: FeeKind::Creator,
+            amount: owed,
+        });
+    }
+
+    // Only carried payouts remain accrued
+    ctx.accounts.bonding_curve.creator_fees_accrued = still_carried;
+
+    Ok(())
+}
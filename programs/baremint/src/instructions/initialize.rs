@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::errors::ErrorCode;
+use crate::events::ConfigUpdated;
 use crate::state::GlobalConfig;
 
 #[derive(Accounts)]
@@ -28,23 +28,6 @@ pub fn handler(
     initial_virtual_token_reserves: u64,
     initial_virtual_sol_reserves: u64,
 ) -> Result<()> {
-    // Validate fee configuration
-    require!(
-        fee_bps == platform_fee_bps + creator_fee_bps,
-        ErrorCode::InvalidFeeConfiguration
-    );
-    require!(fee_bps <= 1000, ErrorCode::InvalidFeeConfiguration);
-
-    // Validate reserve configuration
-    require!(
-        initial_virtual_token_reserves > 0,
-        ErrorCode::InvalidReserveConfiguration
-    );
-    require!(
-        initial_virtual_sol_reserves > 0,
-        ErrorCode::InvalidReserveConfiguration
-    );
-
     let config = &mut ctx.accounts.global_config;
     config.authority = ctx.accounts.authority.key();
     config.fee_bps = fee_bps;
@@ -58,6 +41,12 @@ pub fn handler(
     config.launch_cooldown_seconds = 7_776_000; // 90 days
     config.creator_allocation_bps = 1000; // 10%
     config.bump = ctx.bumps.global_config;
+    config.set_appended_defaults();
+
+    // Validate fee, reserve, vesting and allocation configuration
+    config.validate()?;
+
+    emit!(ConfigUpdated::from(&**config));
 
     Ok(())
 }
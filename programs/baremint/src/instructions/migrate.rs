@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::ConfigUpdated;
+use crate::state::{
+    BondingCurve, CurveKind, GlobalConfig, TradingStatus, VestingAccount, VestingBeneficiary,
+    WalletShare,
+};
+
+/// Grow a program account created under an older, shorter layout to `new_len`,
+/// topping it up to rent exemption from `payer`. The new bytes are zeroed, so
+/// the account deserializes under the current layout afterwards.
+fn grow_legacy_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    account.resize(new_len)?;
+    Ok(())
+}
+
+// --- Global Config ---
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Still in the original layout, so it cannot be loaded as
+    /// `Account<GlobalConfig>`. The PDA seeds and owner are checked here; the
+    /// discriminator, size and authority are checked in the handler.
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump,
+        owner = crate::ID,
+    )]
+    pub global_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let info = ctx.accounts.global_config.to_account_info();
+    require!(
+        info.data_len() == GlobalConfig::LEGACY_SPACE,
+        ErrorCode::AccountAlreadyMigrated
+    );
+
+    grow_legacy_account(
+        &info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        8 + GlobalConfig::INIT_SPACE,
+    )?;
+
+    let mut config = GlobalConfig::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        ErrorCode::Unauthorized
+    );
+
+    config.set_appended_defaults();
+    // The new launch bounds must admit the defaults the platform already uses
+    config.max_creator_allocation_bps = config
+        .max_creator_allocation_bps
+        .max(config.creator_allocation_bps);
+    config.min_vesting_cliff_seconds = config
+        .min_vesting_cliff_seconds
+        .min(config.vesting_cliff_seconds);
+    config.min_vesting_duration_seconds = config
+        .min_vesting_duration_seconds
+        .min(config.vesting_duration_seconds);
+    config.validate()?;
+
+    config.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit!(ConfigUpdated::from(&config));
+
+    Ok(())
+}
+
+// --- Bonding Curve and Vesting ---
+
+#[derive(Accounts)]
+pub struct MigrateCurve<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        constraint = authority.key() == global_config.authority @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    /// CHECK: Still in the original layout, so it cannot be loaded as
+    /// `Account<BondingCurve>`. The PDA seeds and owner are checked here; the
+    /// discriminator and size are checked in the handler.
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", token_mint.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub bonding_curve: UncheckedAccount<'info>,
+
+    /// CHECK: Same as `bonding_curve`, for the launch's vesting account.
+    #[account(
+        mut,
+        seeds = [b"vesting", token_mint.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub vesting_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a launch's bonding curve and vesting account from the original layout
+/// and backfill the appended fields. Original launches are constant product
+/// curves with no launch window, vesting to the creator alone on the platform
+/// schedule, which is snapshotted here as it stands.
+pub fn handler_migrate_curve(ctx: Context<MigrateCurve>) -> Result<()> {
+    let curve_info = ctx.accounts.bonding_curve.to_account_info();
+    let vesting_info = ctx.accounts.vesting_account.to_account_info();
+    require!(
+        curve_info.data_len() == BondingCurve::LEGACY_SPACE
+            && vesting_info.data_len() == VestingAccount::LEGACY_SPACE,
+        ErrorCode::AccountAlreadyMigrated
+    );
+
+    grow_legacy_account(
+        &curve_info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        8 + BondingCurve::INIT_SPACE,
+    )?;
+    grow_legacy_account(
+        &vesting_info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        8 + VestingAccount::INIT_SPACE,
+    )?;
+
+    let mut curve = BondingCurve::try_deserialize(&mut &curve_info.try_borrow_data()?[..])?;
+    let mut vesting = VestingAccount::try_deserialize(&mut &vesting_info.try_borrow_data()?[..])?;
+    let config = &ctx.accounts.global_config;
+
+    curve.burn_price_updated_at = 0;
+    curve.trading_status = TradingStatus::Active;
+    curve.launch_window_ends_at = 0;
+    curve.max_tokens_per_wallet = 0;
+    curve.max_sol_per_tx = 0;
+    curve.curve_kind = CurveKind::ConstantProduct;
+    // Everything not sold to the curve went to vesting at launch
+    curve.curve_supply = curve
+        .token_total_supply
+        .checked_sub(vesting.total_allocation)
+        .ok_or(ErrorCode::MathOverflow)?;
+    curve.tokens_sold = curve
+        .curve_supply
+        .checked_sub(curve.real_token_reserves)
+        .ok_or(ErrorCode::MathOverflow)?;
+    curve.curve_start_price = 0;
+    curve.curve_end_price = 0;
+
+    vesting.allocation_bps = u16::try_from(
+        (vesting.total_allocation as u128)
+            .checked_mul(10_000)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_div(curve.token_total_supply as u128)
+            .ok_or(ErrorCode::MathOverflow)?,
+    )
+    .map_err(|_| error!(ErrorCode::MathOverflow))?;
+    vesting.cliff_seconds = config.vesting_cliff_seconds;
+    vesting.duration_seconds = config.vesting_duration_seconds;
+    vesting.claim_interval_seconds = config.vesting_claim_interval_seconds;
+    vesting.beneficiaries = vec![VestingBeneficiary {
+        share: WalletShare {
+            wallet: vesting.creator,
+            share_bps: 10_000,
+        },
+        claimed_amount: vesting.claimed_amount,
+    }];
+
+    curve.try_serialize(&mut &mut curve_info.try_borrow_mut_data()?[..])?;
+    vesting.try_serialize(&mut &mut vesting_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
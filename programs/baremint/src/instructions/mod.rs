@@ -1,17 +1,46 @@
+// Each module's `handler` is re-exported under the same name; lib.rs always
+// calls handlers by their module path, and the globs are only for the Accounts
+// structs and the client modules Anchor generates alongside them.
+#![allow(ambiguous_glob_reexports)]
+
+pub mod access_pass;
 pub mod burn_access;
+pub mod burn_access_gift;
 pub mod buy;
+pub mod buy_exact_tokens;
 pub mod claim_vested;
 pub mod create_token;
+pub mod fee_split;
 pub mod initialize;
+pub mod migrate;
+pub mod pause;
+pub mod premium_content;
+pub mod referral;
 pub mod revoke_vesting;
 pub mod sell;
+pub mod sell_for_exact_sol;
+pub mod stake;
+pub mod transfer_authority;
+pub mod update_burn_price;
+pub mod update_config;
 pub mod withdraw_fees;
 
+pub use access_pass::*;
 pub use burn_access::*;
+pub use burn_access_gift::*;
 pub use buy::*;
 pub use claim_vested::*;
 pub use create_token::*;
+pub use fee_split::*;
 pub use initialize::*;
+pub use migrate::*;
+pub use pause::*;
+pub use premium_content::*;
+pub use referral::*;
 pub use revoke_vesting::*;
 pub use sell::*;
+pub use stake::*;
+pub use transfer_authority::*;
+pub use update_burn_price::*;
+pub use update_config::*;
 pub use withdraw_fees::*;
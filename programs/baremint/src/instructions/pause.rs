@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::{PauseUpdated, TradingStatusUpdated};
+use crate::state::{BondingCurve, GlobalConfig, TradingStatus};
+
+// --- Global Emergency Pause ---
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        constraint = authority.key() == global_config.authority @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler_set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.global_config.paused = paused;
+
+    emit!(PauseUpdated {
+        authority: ctx.accounts.authority.key(),
+        paused,
+    });
+
+    Ok(())
+}
+
+// --- Per-Curve Trading Status ---
+
+#[derive(Accounts)]
+pub struct SetTradingStatus<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        constraint = authority.key() == global_config.authority @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", token_mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    pub token_mint: Account<'info, Mint>,
+}
+
+pub fn handler_set_trading_status(
+    ctx: Context<SetTradingStatus>,
+    trading_status: TradingStatus,
+) -> Result<()> {
+    ctx.accounts.bonding_curve.trading_status = trading_status;
+
+    emit!(TradingStatusUpdated {
+        token_mint: ctx.accounts.token_mint.key(),
+        authority: ctx.accounts.authority.key(),
+        trading_status,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::PremiumContentUpdated;
+use crate::state::{BondingCurve, GlobalConfig, PremiumContent};
+
+// --- Register Premium Content ---
+
+#[derive(Accounts)]
+#[instruction(content_id: [u8; 32])]
+pub struct RegisterPremiumContent<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [b"bonding_curve", token_mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        constraint = creator.key() == bonding_curve.creator @ ErrorCode::Unauthorized,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + PremiumContent::INIT_SPACE,
+        seeds = [b"premium_content", token_mint.key().as_ref(), content_id.as_ref()],
+        bump,
+    )]
+    pub premium_content: Account<'info, PremiumContent>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_register_premium_content(
+    ctx: Context<RegisterPremiumContent>,
+    content_id: [u8; 32],
+    burn_sol_price: u64,
+    max_unlocks: u32,
+    expires_at: i64,
+) -> Result<()> {
+    ctx.accounts
+        .global_config
+        .validate_burn_sol_price(burn_sol_price)?;
+    PremiumContent::validate_expires_at(expires_at)?;
+
+    let content = &mut ctx.accounts.premium_content;
+    content.token_mint = ctx.accounts.token_mint.key();
+    content.content_id = content_id;
+    content.burn_sol_price = burn_sol_price;
+    content.max_unlocks = max_unlocks;
+    content.unlock_count = 0;
+    content.expires_at = expires_at;
+    content.bump = ctx.bumps.premium_content;
+
+    emit!(PremiumContentUpdated {
+        token_mint: content.token_mint,
+        content_id,
+        burn_sol_price,
+        max_unlocks,
+        expires_at,
+    });
+
+    Ok(())
+}
+
+// --- Update Premium Content ---
+
+#[derive(Accounts)]
+pub struct UpdatePremiumContent<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [b"bonding_curve", token_mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        constraint = creator.key() == bonding_curve.creator @ ErrorCode::Unauthorized,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"premium_content",
+            token_mint.key().as_ref(),
+            premium_content.content_id.as_ref(),
+        ],
+        bump = premium_content.bump,
+    )]
+    pub premium_content: Account<'info, PremiumContent>,
+}
+
+/// Reprice or reconfigure an existing content item. `unlock_count` is kept, so
+/// lowering `max_unlocks` below it closes further unlocks.
+pub fn handler_update_premium_content(
+    ctx: Context<UpdatePremiumContent>,
+    burn_sol_price: u64,
+    max_unlocks: u32,
+    expires_at: i64,
+) -> Result<()> {
+    ctx.accounts
+        .global_config
+        .validate_burn_sol_price(burn_sol_price)?;
+    PremiumContent::validate_expires_at(expires_at)?;
+
+    let content = &mut ctx.accounts.premium_content;
+    content.burn_sol_price = burn_sol_price;
+    content.max_unlocks = max_unlocks;
+    content.expires_at = expires_at;
+
+    emit!(PremiumContentUpdated {
+        token_mint: content.token_mint,
+        content_id: content.content_id,
+        burn_sol_price,
+        max_unlocks,
+        expires_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::{ReferralFeesClaimed, ReferrerRegistered};
+use crate::state::{GlobalConfig, Referrer};
+
+// --- Register Referrer ---
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Referrer::INIT_SPACE,
+        seeds = [b"referrer", authority.key().as_ref()],
+        bump,
+    )]
+    pub referrer: Account<'info, Referrer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler_register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.authority = ctx.accounts.authority.key();
+    referrer.fees_accrued = 0;
+    referrer.total_earned = 0;
+    referrer.bump = ctx.bumps.referrer;
+
+    emit!(ReferrerRegistered {
+        authority: referrer.authority,
+        referrer: referrer.key(),
+    });
+
+    Ok(())
+}
+
+// --- Claim Referral Fees ---
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"referrer", authority.key().as_ref()],
+        bump = referrer.bump,
+        constraint = authority.key() == referrer.authority @ ErrorCode::Unauthorized,
+    )]
+    pub referrer: Account<'info, Referrer>,
+}
+
+pub fn handler_claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let amount = ctx.accounts.referrer.fees_accrued;
+
+    if amount == 0 {
+        return Ok(());
+    }
+
+    // Transfer lamports from referrer PDA to authority
+    let referrer_info = ctx.accounts.referrer.to_account_info();
+    let authority_info = ctx.accounts.authority.to_account_info();
+
+    // Ensure referrer PDA retains enough lamports for rent exemption
+    let rent = Rent::get()?;
+    let min_lamports = rent.minimum_balance(referrer_info.data_len());
+    let current_lamports = referrer_info.lamports();
+    require!(
+        current_lamports
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathOverflow)?
+            >= min_lamports,
+        ErrorCode::InsufficientReserves
+    );
+
+    **referrer_info.try_borrow_mut_lamports()? -= amount;
+    **authority_info.try_borrow_mut_lamports()? += amount;
+
+    ctx.accounts.referrer.fees_accrued = 0;
+
+    emit!(ReferralFeesClaimed {
+        authority: ctx.accounts.authority.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Carve the referral share out of `platform_fee` and move it from the curve
+/// PDA's lamports to the referrer PDA. Call after the trade's CPIs. Returns
+/// `(platform_fee, referral_fee)` with the platform share net of the referral.
+pub(crate) fn pay_referral<'info>(
+    config: &GlobalConfig,
+    bonding_curve_info: &AccountInfo<'info>,
+    referrer: Option<&mut Account<'info, Referrer>>,
+    platform_fee: u64,
+) -> Result<(u64, u64)> {
+    let Some(referrer) = referrer else {
+        return Ok((platform_fee, 0));
+    };
+
+    let referral_fee = (platform_fee as u128)
+        .checked_mul(config.referral_fee_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / 10_000;
+    let referral_fee = referral_fee as u64;
+    if referral_fee == 0 {
+        return Ok((platform_fee, 0));
+    }
+
+    let referrer_info = referrer.to_account_info();
+    **bonding_curve_info.try_borrow_mut_lamports()? -= referral_fee;
+    **referrer_info.try_borrow_mut_lamports()? += referral_fee;
+
+    referrer.fees_accrued = referrer
+        .fees_accrued
+        .checked_add(referral_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+    referrer.total_earned = referrer
+        .total_earned
+        .checked_add(referral_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let platform_fee = platform_fee
+        .checked_sub(referral_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+    Ok((platform_fee, referral_fee))
+}
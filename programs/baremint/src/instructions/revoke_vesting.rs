@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
 
 use crate::errors::ErrorCode;
+use crate::events::VestingRevoked;
 use crate::state::{BondingCurve, GlobalConfig, VestingAccount};
 
 #[derive(Accounts)]
@@ -91,5 +92,12 @@ pub fn handler(ctx: Context<RevokeVesting>) -> Result<()> {
     let vesting = &mut ctx.accounts.vesting_account;
     vesting.is_revoked = true;
 
+    emit!(VestingRevoked {
+        token_mint: vesting.token_mint,
+        creator: vesting.creator,
+        tokens_burned: unvested,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
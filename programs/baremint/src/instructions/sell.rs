@@ -1,18 +1,28 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::errors::ErrorCode;
+use crate::events::{Trade, TradeSide};
+use crate::instructions::referral::pay_referral;
 use crate::math;
-use crate::state::{BondingCurve, GlobalConfig};
+use crate::state::{BondingCurve, GlobalConfig, Referrer, TradingStatus};
 
 #[derive(Accounts)]
 pub struct Sell<'info> {
+    /// Owns the tokens being sold and receives the SOL proceeds
     #[account(mut)]
     pub seller: Signer<'info>,
 
+    /// Optional transaction sponsor. When the proceeds would leave a seller
+    /// holding no SOL below rent exemption, the sponsor tops the seller up.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
     #[account(
         seeds = [b"global_config"],
         bump = global_config.bump,
+        constraint = !global_config.paused @ ErrorCode::ProgramPaused,
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
@@ -20,6 +30,7 @@ pub struct Sell<'info> {
         mut,
         seeds = [b"bonding_curve", token_mint.key().as_ref()],
         bump = bonding_curve.bump,
+        constraint = bonding_curve.trading_status != TradingStatus::Halted @ ErrorCode::TradingHalted,
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
 
@@ -41,101 +52,194 @@ pub struct Sell<'info> {
     )]
     pub seller_token_account: Account<'info, TokenAccount>,
 
+    /// Optional registered referrer credited with part of the platform fee
+    #[account(
+        mut,
+        constraint = referrer.authority != seller.key() @ ErrorCode::SelfReferral,
+    )]
+    pub referrer: Option<Account<'info, Referrer>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Sell>, token_amount: u64, min_sol_out: u64) -> Result<()> {
-    let config = &ctx.accounts.global_config;
-    let bonding_curve = &ctx.accounts.bonding_curve;
-
-    // Calculate gross SOL output from constant product curve
-    let gross_sol_out = math::calculate_sell_sol(
-        bonding_curve.virtual_sol_reserves,
-        bonding_curve.virtual_token_reserves,
-        token_amount,
-    )?;
-
-    // Calculate fees on the gross output
-    let total_fee = math::calculate_fee(gross_sol_out, config.fee_bps)?;
-    let platform_fee = total_fee / 2;
-    let creator_fee = total_fee
-        .checked_sub(platform_fee)
-        .ok_or(ErrorCode::MathOverflow)?;
-
-    // Net SOL to send to seller
-    let net_sol_out = gross_sol_out
-        .checked_sub(total_fee)
-        .ok_or(ErrorCode::MathOverflow)?;
-
-    // Slippage check
-    require!(net_sol_out >= min_sol_out, ErrorCode::SlippageExceeded);
-
-    // Check sufficient real SOL reserves for gross amount
-    require!(
-        bonding_curve.real_sol_reserves >= gross_sol_out,
-        ErrorCode::InsufficientReserves
-    );
-
-    // Transfer tokens from seller to curve
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.seller_token_account.to_account_info(),
-                to: ctx.accounts.curve_token_account.to_account_info(),
-                authority: ctx.accounts.seller.to_account_info(),
-            },
-        ),
-        token_amount,
-    )?;
-
-    // Transfer net_sol_out from bonding_curve PDA to seller via lamport manipulation.
-    // The program owns the bonding_curve PDA so we can directly modify lamports.
-    let bonding_curve_info = ctx.accounts.bonding_curve.to_account_info();
-    let seller_info = ctx.accounts.seller.to_account_info();
-
-    // Ensure bonding_curve retains enough lamports for rent exemption
-    let rent = Rent::get()?;
-    let min_lamports = rent.minimum_balance(bonding_curve_info.data_len());
-    let current_lamports = bonding_curve_info.lamports();
-    require!(
-        current_lamports
-            .checked_sub(net_sol_out)
-            .ok_or(ErrorCode::MathOverflow)?
-            >= min_lamports,
-        ErrorCode::InsufficientReserves
-    );
-
-    **bonding_curve_info.try_borrow_mut_lamports()? -= net_sol_out;
-    **seller_info.try_borrow_mut_lamports()? += net_sol_out;
-
-    // Update bonding curve state
-    let bonding_curve = &mut ctx.accounts.bonding_curve;
-    bonding_curve.virtual_sol_reserves = bonding_curve
-        .virtual_sol_reserves
-        .checked_sub(gross_sol_out)
-        .ok_or(ErrorCode::MathOverflow)?;
-    bonding_curve.virtual_token_reserves = bonding_curve
-        .virtual_token_reserves
-        .checked_add(token_amount)
-        .ok_or(ErrorCode::MathOverflow)?;
-    bonding_curve.real_sol_reserves = bonding_curve
-        .real_sol_reserves
-        .checked_sub(gross_sol_out)
-        .ok_or(ErrorCode::MathOverflow)?;
-    bonding_curve.real_token_reserves = bonding_curve
-        .real_token_reserves
-        .checked_add(token_amount)
-        .ok_or(ErrorCode::MathOverflow)?;
-    bonding_curve.platform_fees_accrued = bonding_curve
-        .platform_fees_accrued
-        .checked_add(platform_fee)
-        .ok_or(ErrorCode::MathOverflow)?;
-    bonding_curve.creator_fees_accrued = bonding_curve
-        .creator_fees_accrued
-        .checked_add(creator_fee)
-        .ok_or(ErrorCode::MathOverflow)?;
-
-    Ok(())
+pub fn handler(
+    ctx: Context<Sell>,
+    token_amount: u64,
+    min_sol_out: u64,
+    deadline: Option<i64>,
+) -> Result<()> {
+    ctx.accounts.execute(token_amount, min_sol_out, deadline)
+}
+
+impl<'info> Sell<'info> {
+    /// A system account may not end a transaction funded but below rent
+    /// exemption, so a seller holding no SOL could not receive small proceeds.
+    /// The optional payer covers the shortfall. Without a payer nothing changes
+    /// and such a sell still fails.
+    fn sponsor_seller_rent(&self, proceeds: u64) -> Result<()> {
+        let Some(payer) = &self.payer else {
+            return Ok(());
+        };
+        let balance_after = self
+            .seller
+            .lamports()
+            .checked_add(proceeds)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let shortfall = Rent::get()?
+            .minimum_balance(self.seller.data_len())
+            .saturating_sub(balance_after);
+        if balance_after == 0 || shortfall == 0 {
+            return Ok(());
+        }
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: self.seller.to_account_info(),
+                },
+            ),
+            shortfall,
+        )
+    }
+
+    /// Sell `token_amount` tokens for at least `min_sol_out` net lamports: move
+    /// tokens and SOL, update curve state and emit the Trade event. Shared by
+    /// sell and sell_for_exact_sol.
+    pub(crate) fn execute(
+        &mut self,
+        token_amount: u64,
+        min_sol_out: u64,
+        deadline: Option<i64>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // Reject stale transactions (e.g. retried long after the quote was shown)
+        if let Some(deadline) = deadline {
+            require!(now <= deadline, ErrorCode::DeadlineExceeded);
+        }
+
+        let config = &self.global_config;
+        let bonding_curve = &self.bonding_curve;
+
+        // Calculate gross SOL output along the curve's shape
+        let gross_sol_out = math::curve_sell_sol(&bonding_curve.curve_params(), token_amount)?;
+
+        // Calculate fees on the gross output
+        let total_fee = math::calculate_fee(gross_sol_out, config.fee_bps)?;
+        let (platform_fee, creator_fee) =
+            math::split_fee(total_fee, config.platform_fee_bps, config.creator_fee_bps)?;
+
+        // Net SOL to send to seller
+        let net_sol_out = gross_sol_out
+            .checked_sub(total_fee)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        // Slippage check
+        require!(net_sol_out >= min_sol_out, ErrorCode::SlippageExceeded);
+
+        // Check sufficient real SOL reserves for gross amount
+        require!(
+            bonding_curve.real_sol_reserves >= gross_sol_out,
+            ErrorCode::InsufficientReserves
+        );
+
+        // Transfer tokens from seller to curve
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.seller_token_account.to_account_info(),
+                    to: self.curve_token_account.to_account_info(),
+                    authority: self.seller.to_account_info(),
+                },
+            ),
+            token_amount,
+        )?;
+
+        self.sponsor_seller_rent(net_sol_out)?;
+
+        // Transfer net_sol_out from bonding_curve PDA to seller via lamport manipulation.
+        // The program owns the bonding_curve PDA so we can directly modify lamports.
+        let bonding_curve_info = self.bonding_curve.to_account_info();
+        let seller_info = self.seller.to_account_info();
+
+        // Ensure bonding_curve retains enough lamports for rent exemption
+        let rent = Rent::get()?;
+        let min_lamports = rent.minimum_balance(bonding_curve_info.data_len());
+        let current_lamports = bonding_curve_info.lamports();
+        require!(
+            current_lamports
+                .checked_sub(net_sol_out)
+                .ok_or(ErrorCode::MathOverflow)?
+                >= min_lamports,
+            ErrorCode::InsufficientReserves
+        );
+
+        **bonding_curve_info.try_borrow_mut_lamports()? -= net_sol_out;
+        **seller_info.try_borrow_mut_lamports()? += net_sol_out;
+
+        // Referral share comes out of the platform fee retained by the curve
+        let (platform_fee, referral_fee) = pay_referral(
+            &self.global_config,
+            &bonding_curve_info,
+            self.referrer.as_mut(),
+            platform_fee,
+        )?;
+        let referrer = self
+            .referrer
+            .as_ref()
+            .map_or(Pubkey::default(), |r| r.authority);
+
+        // Update bonding curve state
+        let bonding_curve = &mut self.bonding_curve;
+        bonding_curve.virtual_sol_reserves = bonding_curve
+            .virtual_sol_reserves
+            .checked_sub(gross_sol_out)
+            .ok_or(ErrorCode::MathOverflow)?;
+        bonding_curve.virtual_token_reserves = bonding_curve
+            .virtual_token_reserves
+            .checked_add(token_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        bonding_curve.real_sol_reserves = bonding_curve
+            .real_sol_reserves
+            .checked_sub(gross_sol_out)
+            .ok_or(ErrorCode::MathOverflow)?;
+        bonding_curve.real_token_reserves = bonding_curve
+            .real_token_reserves
+            .checked_add(token_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        // Saturates because constant product curves also take back tokens that
+        // never came from the curve (claimed vesting)
+        bonding_curve.tokens_sold = bonding_curve.tokens_sold.saturating_sub(token_amount);
+        bonding_curve.platform_fees_accrued = bonding_curve
+            .platform_fees_accrued
+            .checked_add(platform_fee)
+            .ok_or(ErrorCode::MathOverflow)?;
+        bonding_curve.creator_fees_accrued = bonding_curve
+            .creator_fees_accrued
+            .checked_add(creator_fee)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit!(Trade {
+            token_mint: bonding_curve.token_mint,
+            trader: self.seller.key(),
+            side: TradeSide::Sell,
+            sol_amount: net_sol_out,
+            token_amount,
+            platform_fee,
+            creator_fee,
+            referrer,
+            referral_fee,
+            virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+            virtual_token_reserves: bonding_curve.virtual_token_reserves,
+            real_sol_reserves: bonding_curve.real_sol_reserves,
+            real_token_reserves: bonding_curve.real_token_reserves,
+            tokens_sold: bonding_curve.tokens_sold,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
 }
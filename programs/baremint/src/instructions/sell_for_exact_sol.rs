@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::sell::Sell;
+use crate::math;
+
+/// Sell the minimum number of tokens (at most `max_tokens_in`) that nets the
+/// seller at least `sol_out` lamports after fees. Uses the same accounts as sell.
+/// Curve rounding can leave the seller a few lamports above `sol_out`.
+pub fn handler(
+    ctx: Context<Sell>,
+    sol_out: u64,
+    max_tokens_in: u64,
+    deadline: Option<i64>,
+) -> Result<()> {
+    let config = &ctx.accounts.global_config;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    // Gross up for the fee that sell deducts from the curve output
+    let gross_sol_out = math::calculate_gross_for_net(sol_out, config.fee_bps)?;
+
+    // Minimum tokens whose curve output covers the gross amount (rounded up)
+    let token_amount =
+        math::curve_sell_tokens_for_sol(&bonding_curve.curve_params(), gross_sol_out)?;
+
+    // Slippage check on tokens given up
+    require!(token_amount <= max_tokens_in, ErrorCode::SlippageExceeded);
+
+    ctx.accounts.execute(token_amount, sol_out, deadline)
+}
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::{Staked, Unstaked};
+use crate::state::{BondingCurve, GlobalConfig, StakePosition};
+
+// --- Stake ---
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        constraint = !global_config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Only tokens launched on the platform can be staked
+    #[account(
+        seeds = [b"bonding_curve", token_mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + StakePosition::INIT_SPACE,
+        seeds = [b"stake_position", token_mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        token::mint = token_mint,
+        token::authority = stake_position,
+        seeds = [b"stake_vault", token_mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lock `amount` tokens in the owner's vault. Every stake restarts the lock and
+/// snapshots the current unstake cooldown, so tokens cannot be topped up and
+/// withdrawn within the same window.
+pub fn handler_stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidStakeAmount);
+    require!(
+        ctx.accounts.owner_token_account.amount >= amount,
+        ErrorCode::InsufficientTokens
+    );
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let position = &mut ctx.accounts.stake_position;
+    position.owner = ctx.accounts.owner.key();
+    position.token_mint = ctx.accounts.token_mint.key();
+    position.amount = position
+        .amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    position.locked_since = timestamp;
+    position.unstake_cooldown_seconds = ctx.accounts.global_config.unstake_cooldown_seconds;
+    position.bump = ctx.bumps.stake_position;
+
+    emit!(Staked {
+        token_mint: position.token_mint,
+        owner: position.owner,
+        amount,
+        total_staked: position.amount,
+        unlocks_at: timestamp
+            .checked_add(position.unstake_cooldown_seconds)
+            .ok_or(ErrorCode::MathOverflow)?,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+// --- Unstake ---
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    pub owner: Signer<'info>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"stake_position", token_mint.key().as_ref(), owner.key().as_ref()],
+        bump = stake_position.bump,
+        constraint = owner.key() == stake_position.owner @ ErrorCode::Unauthorized,
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(
+        mut,
+        seeds = [b"stake_vault", token_mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = stake_position,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Release `amount` staked tokens once the cooldown since the last stake has
+/// elapsed. Not gated by the emergency pause so holders can always exit.
+pub fn handler_unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+    let position = &ctx.accounts.stake_position;
+    require!(amount > 0, ErrorCode::InvalidStakeAmount);
+    require!(position.amount >= amount, ErrorCode::InsufficientTokens);
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let unlocks_at = position
+        .locked_since
+        .checked_add(position.unstake_cooldown_seconds)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(timestamp >= unlocks_at, ErrorCode::StakeLocked);
+
+    // Transfer from vault back to owner (stake_position PDA signs)
+    let token_mint_key = ctx.accounts.token_mint.key();
+    let owner_key = ctx.accounts.owner.key();
+    let bump = position.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"stake_position",
+        token_mint_key.as_ref(),
+        owner_key.as_ref(),
+        &[bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.stake_position.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    let position = &mut ctx.accounts.stake_position;
+    position.amount = position
+        .amount
+        .checked_sub(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(Unstaked {
+        token_mint: position.token_mint,
+        owner: position.owner,
+        amount,
+        total_staked: position.amount,
+        timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::{AuthorityProposed, AuthorityTransferred};
+use crate::state::GlobalConfig;
+
+// --- Propose Authority ---
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        constraint = authority.key() == global_config.authority @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// Stage `new_authority` as the pending authority. Control only moves once the
+/// new key signs accept_authority, so a mistyped pubkey cannot lock out the
+/// platform. Proposing `Pubkey::default()` cancels a pending transfer.
+pub fn handler_propose_authority(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    ctx.accounts.global_config.pending_authority = new_authority;
+
+    emit!(AuthorityProposed {
+        authority: ctx.accounts.authority.key(),
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
+
+// --- Accept Authority ---
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        constraint = global_config.pending_authority != Pubkey::default() @ ErrorCode::NoPendingAuthority,
+        constraint = new_authority.key() == global_config.pending_authority @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler_accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+    let previous_authority = config.authority;
+    config.authority = ctx.accounts.new_authority.key();
+    config.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferred {
+        previous_authority,
+        new_authority: config.authority,
+    });
+
+    Ok(())
+}
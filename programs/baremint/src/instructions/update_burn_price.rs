@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::BurnPriceUpdated;
+use crate::state::{BondingCurve, GlobalConfig};
+
+#[derive(Accounts)]
+pub struct UpdateBurnPrice<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", token_mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        constraint = creator.key() == bonding_curve.creator @ ErrorCode::Unauthorized,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    pub token_mint: Account<'info, Mint>,
+}
+
+/// Change the SOL-denominated burn price. Setting 0 disables burns; any other
+/// value must fall within the platform bounds. Updates are rate-limited by
+/// `burn_price_update_cooldown_seconds`.
+pub fn handler(ctx: Context<UpdateBurnPrice>, burn_sol_price: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.global_config;
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+
+    config.validate_burn_sol_price(burn_sol_price)?;
+
+    // Rate limit (the first update after launch is always allowed)
+    if bonding_curve.burn_price_updated_at > 0 {
+        let elapsed = clock
+            .unix_timestamp
+            .checked_sub(bonding_curve.burn_price_updated_at)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(
+            elapsed >= config.burn_price_update_cooldown_seconds,
+            ErrorCode::BurnPriceCooldownNotElapsed
+        );
+    }
+
+    let previous_burn_sol_price = bonding_curve.burn_sol_price;
+    bonding_curve.burn_sol_price = burn_sol_price;
+    bonding_curve.burn_price_updated_at = clock.unix_timestamp;
+
+    emit!(BurnPriceUpdated {
+        token_mint: ctx.accounts.token_mint.key(),
+        creator: ctx.accounts.creator.key(),
+        previous_burn_sol_price,
+        burn_sol_price,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ConfigUpdated;
+use crate::state::GlobalConfig;
+
+/// Partial update of GlobalConfig. `None` leaves the current value untouched.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateConfigParams {
+    pub fee_bps: Option<u16>,
+    pub platform_fee_bps: Option<u16>,
+    pub creator_fee_bps: Option<u16>,
+    pub initial_virtual_token_reserves: Option<u64>,
+    pub initial_virtual_sol_reserves: Option<u64>,
+    pub vesting_cliff_seconds: Option<i64>,
+    pub vesting_duration_seconds: Option<i64>,
+    pub vesting_claim_interval_seconds: Option<i64>,
+    pub launch_cooldown_seconds: Option<i64>,
+    pub creator_allocation_bps: Option<u16>,
+    pub min_burn_sol_price: Option<u64>,
+    pub max_burn_sol_price: Option<u64>,
+    pub burn_price_update_cooldown_seconds: Option<i64>,
+    pub unstake_cooldown_seconds: Option<i64>,
+    pub max_initial_buy_bps: Option<u16>,
+    pub launch_window_seconds: Option<i64>,
+    pub launch_max_tokens_per_wallet: Option<u64>,
+    pub launch_max_sol_per_tx: Option<u64>,
+    pub referral_fee_bps: Option<u16>,
+    pub allowed_curve_kinds: Option<u8>,
+    pub min_total_supply: Option<u64>,
+    pub max_total_supply: Option<u64>,
+    pub min_token_decimals: Option<u8>,
+    pub max_token_decimals: Option<u8>,
+    pub max_creator_allocation_bps: Option<u16>,
+    pub min_vesting_cliff_seconds: Option<i64>,
+    pub min_vesting_duration_seconds: Option<i64>,
+    pub fee_split_timelock_seconds: Option<i64>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        constraint = authority.key() == global_config.authority @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+
+    if let Some(fee_bps) = params.fee_bps {
+        config.fee_bps = fee_bps;
+    }
+    if let Some(platform_fee_bps) = params.platform_fee_bps {
+        config.platform_fee_bps = platform_fee_bps;
+    }
+    if let Some(creator_fee_bps) = params.creator_fee_bps {
+        config.creator_fee_bps = creator_fee_bps;
+    }
+    if let Some(reserves) = params.initial_virtual_token_reserves {
+        config.initial_virtual_token_reserves = reserves;
+    }
+    if let Some(reserves) = params.initial_virtual_sol_reserves {
+        config.initial_virtual_sol_reserves = reserves;
+    }
+    if let Some(cliff) = params.vesting_cliff_seconds {
+        config.vesting_cliff_seconds = cliff;
+    }
+    if let Some(duration) = params.vesting_duration_seconds {
+        config.vesting_duration_seconds = duration;
+    }
+    if let Some(interval) = params.vesting_claim_interval_seconds {
+        config.vesting_claim_interval_seconds = interval;
+    }
+    if let Some(cooldown) = params.launch_cooldown_seconds {
+        config.launch_cooldown_seconds = cooldown;
+    }
+    if let Some(allocation_bps) = params.creator_allocation_bps {
+        config.creator_allocation_bps = allocation_bps;
+    }
+    if let Some(min_price) = params.min_burn_sol_price {
+        config.min_burn_sol_price = min_price;
+    }
+    if let Some(max_price) = params.max_burn_sol_price {
+        config.max_burn_sol_price = max_price;
+    }
+    if let Some(cooldown) = params.burn_price_update_cooldown_seconds {
+        config.burn_price_update_cooldown_seconds = cooldown;
+    }
+    if let Some(cooldown) = params.unstake_cooldown_seconds {
+        config.unstake_cooldown_seconds = cooldown;
+    }
+    if let Some(max_bps) = params.max_initial_buy_bps {
+        config.max_initial_buy_bps = max_bps;
+    }
+    if let Some(window) = params.launch_window_seconds {
+        config.launch_window_seconds = window;
+    }
+    if let Some(max_tokens) = params.launch_max_tokens_per_wallet {
+        config.launch_max_tokens_per_wallet = max_tokens;
+    }
+    if let Some(max_sol) = params.launch_max_sol_per_tx {
+        config.launch_max_sol_per_tx = max_sol;
+    }
+    if let Some(referral_bps) = params.referral_fee_bps {
+        config.referral_fee_bps = referral_bps;
+    }
+    if let Some(curve_kinds) = params.allowed_curve_kinds {
+        config.allowed_curve_kinds = curve_kinds;
+    }
+    if let Some(min_supply) = params.min_total_supply {
+        config.min_total_supply = min_supply;
+    }
+    if let Some(max_supply) = params.max_total_supply {
+        config.max_total_supply = max_supply;
+    }
+    if let Some(min_decimals) = params.min_token_decimals {
+        config.min_token_decimals = min_decimals;
+    }
+    if let Some(max_decimals) = params.max_token_decimals {
+        config.max_token_decimals = max_decimals;
+    }
+    if let Some(max_allocation_bps) = params.max_creator_allocation_bps {
+        config.max_creator_allocation_bps = max_allocation_bps;
+    }
+    if let Some(min_cliff) = params.min_vesting_cliff_seconds {
+        config.min_vesting_cliff_seconds = min_cliff;
+    }
+    if let Some(min_duration) = params.min_vesting_duration_seconds {
+        config.min_vesting_duration_seconds = min_duration;
+    }
+    if let Some(timelock) = params.fee_split_timelock_seconds {
+        config.fee_split_timelock_seconds = timelock;
+    }
+
+    // Validate the resulting config as a whole (e.g. fee_bps must still equal the
+    // sum of the shares when only one of them changed)
+    config.validate()?;
+
+    emit!(ConfigUpdated::from(&**config));
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 
 use crate::errors::ErrorCode;
+use crate::events::{FeeKind, FeesWithdrawn};
 use crate::state::{BondingCurve, GlobalConfig};
 
 // --- Withdraw Platform Fees ---
@@ -57,6 +58,13 @@ pub fn handler_withdraw_platform_fees(ctx: Context<WithdrawPlatformFees>) -> Res
     // Reset accrued fees
     ctx.accounts.bonding_curve.platform_fees_accrued = 0;
 
+    emit!(FeesWithdrawn {
+        token_mint: ctx.accounts.token_mint.key(),
+        recipient: ctx.accounts.authority.key(),
+        kind: FeeKind::Platform,
+        amount,
+    });
+
     Ok(())
 }
 
@@ -76,6 +84,15 @@ pub struct WithdrawCreatorFees<'info> {
     pub bonding_curve: Account<'info, BondingCurve>,
 
     pub token_mint: Account<'info, Mint>,
+
+    /// CHECK: Only its absence is checked; once a fee split exists, creator
+    /// fees go out through distribute_creator_fees instead.
+    #[account(
+        seeds = [b"fee_split", token_mint.key().as_ref()],
+        bump,
+        constraint = fee_split.data_is_empty() @ ErrorCode::FeeSplitActive,
+    )]
+    pub fee_split: UncheckedAccount<'info>,
 }
 
 pub fn handler_withdraw_creator_fees(ctx: Context<WithdrawCreatorFees>) -> Result<()> {
@@ -107,5 +124,12 @@ pub fn handler_withdraw_creator_fees(ctx: Context<WithdrawCreatorFees>) -> Resul
     // Reset accrued fees
     ctx.accounts.bonding_curve.creator_fees_accrued = 0;
 
+    emit!(FeesWithdrawn {
+        token_mint: ctx.accounts.token_mint.key(),
+        recipient: ctx.accounts.creator.key(),
+        kind: FeeKind::Creator,
+        amount,
+    });
+
     Ok(())
 }
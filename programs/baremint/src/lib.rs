@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod math;
 pub mod state;
 
 use instructions::*;
+use state::{TradingStatus, WalletShare};
 
 declare_id!("FTAssMPiQ8EQUeJA4Rnu6c71maCrUCdnvGetWnVdTXTG");
 
@@ -31,20 +33,155 @@ pub mod baremint {
         )
     }
 
-    pub fn create_token(ctx: Context<CreateToken>, burn_sol_price: u64) -> Result<()> {
-        instructions::create_token::handler(ctx, burn_sol_price)
+    pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+        instructions::update_config::handler(ctx, params)
     }
 
-    pub fn buy(ctx: Context<Buy>, sol_amount: u64, min_tokens_out: u64) -> Result<()> {
-        instructions::buy::handler(ctx, sol_amount, min_tokens_out)
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate::handler_migrate_config(ctx)
     }
 
-    pub fn sell(ctx: Context<Sell>, token_amount: u64, min_sol_out: u64) -> Result<()> {
-        instructions::sell::handler(ctx, token_amount, min_sol_out)
+    pub fn migrate_curve(ctx: Context<MigrateCurve>) -> Result<()> {
+        instructions::migrate::handler_migrate_curve(ctx)
     }
 
-    pub fn burn_for_access(ctx: Context<BurnAccess>) -> Result<()> {
-        instructions::burn_access::handler(ctx)
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::transfer_authority::handler_propose_authority(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::transfer_authority::handler_accept_authority(ctx)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::pause::handler_set_paused(ctx, paused)
+    }
+
+    pub fn set_trading_status(
+        ctx: Context<SetTradingStatus>,
+        trading_status: TradingStatus,
+    ) -> Result<()> {
+        instructions::pause::handler_set_trading_status(ctx, trading_status)
+    }
+
+    pub fn create_token(ctx: Context<CreateToken>, params: CreateTokenParams) -> Result<()> {
+        instructions::create_token::handler(ctx, params)
+    }
+
+    pub fn buy(
+        ctx: Context<Buy>,
+        sol_amount: u64,
+        min_tokens_out: u64,
+        deadline: Option<i64>,
+    ) -> Result<()> {
+        instructions::buy::handler(ctx, sol_amount, min_tokens_out, deadline)
+    }
+
+    pub fn buy_exact_tokens(
+        ctx: Context<Buy>,
+        token_amount: u64,
+        max_sol_in: u64,
+        deadline: Option<i64>,
+    ) -> Result<()> {
+        instructions::buy_exact_tokens::handler(ctx, token_amount, max_sol_in, deadline)
+    }
+
+    pub fn sell(
+        ctx: Context<Sell>,
+        token_amount: u64,
+        min_sol_out: u64,
+        deadline: Option<i64>,
+    ) -> Result<()> {
+        instructions::sell::handler(ctx, token_amount, min_sol_out, deadline)
+    }
+
+    pub fn sell_for_exact_sol(
+        ctx: Context<Sell>,
+        sol_out: u64,
+        max_tokens_in: u64,
+        deadline: Option<i64>,
+    ) -> Result<()> {
+        instructions::sell_for_exact_sol::handler(ctx, sol_out, max_tokens_in, deadline)
+    }
+
+    pub fn update_burn_price(ctx: Context<UpdateBurnPrice>, burn_sol_price: u64) -> Result<()> {
+        instructions::update_burn_price::handler(ctx, burn_sol_price)
+    }
+
+    pub fn register_premium_content(
+        ctx: Context<RegisterPremiumContent>,
+        content_id: [u8; 32],
+        burn_sol_price: u64,
+        max_unlocks: u32,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::premium_content::handler_register_premium_content(
+            ctx,
+            content_id,
+            burn_sol_price,
+            max_unlocks,
+            expires_at,
+        )
+    }
+
+    pub fn update_premium_content(
+        ctx: Context<UpdatePremiumContent>,
+        burn_sol_price: u64,
+        max_unlocks: u32,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::premium_content::handler_update_premium_content(
+            ctx,
+            burn_sol_price,
+            max_unlocks,
+            expires_at,
+        )
+    }
+
+    pub fn burn_for_access(ctx: Context<BurnAccess>, content_id: [u8; 32]) -> Result<()> {
+        instructions::burn_access::handler(ctx, content_id)
+    }
+
+    pub fn burn_for_access_gift(
+        ctx: Context<BurnAccessGift>,
+        beneficiary: Pubkey,
+        content_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::burn_access_gift::handler(ctx, beneficiary, content_id)
+    }
+
+    pub fn set_pass_tier(
+        ctx: Context<SetPassTier>,
+        tier: u8,
+        burn_sol_price: u64,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        instructions::access_pass::handler_set_pass_tier(
+            ctx,
+            tier,
+            burn_sol_price,
+            duration_seconds,
+        )
+    }
+
+    pub fn burn_for_pass(ctx: Context<BurnForPass>, tier: u8) -> Result<()> {
+        instructions::access_pass::handler_burn_for_pass(ctx, tier)
+    }
+
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        instructions::stake::handler_stake(ctx, amount)
+    }
+
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        instructions::stake::handler_unstake(ctx, amount)
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::referral::handler_register_referrer(ctx)
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        instructions::referral::handler_claim_referral_fees(ctx)
     }
 
     pub fn withdraw_platform_fees(ctx: Context<WithdrawPlatformFees>) -> Result<()> {
@@ -55,6 +192,20 @@ pub mod baremint {
         instructions::withdraw_fees::handler_withdraw_creator_fees(ctx)
     }
 
+    pub fn set_fee_split(ctx: Context<SetFeeSplit>, recipients: Vec<WalletShare>) -> Result<()> {
+        instructions::fee_split::handler_set_fee_split(ctx, recipients)
+    }
+
+    pub fn apply_fee_split(ctx: Context<ApplyFeeSplit>) -> Result<()> {
+        instructions::fee_split::handler_apply_fee_split(ctx)
+    }
+
+    pub fn distribute_creator_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeCreatorFees<'info>>,
+    ) -> Result<()> {
+        instructions::fee_split::handler_distribute_creator_fees(ctx)
+    }
+
     pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
         instructions::claim_vested::handler(ctx)
     }
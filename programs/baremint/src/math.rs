@@ -1,6 +1,29 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ErrorCode;
+use crate::state::CurveKind;
+
+/// Total supply (1B tokens with 6 decimals) that `initial_virtual_token_reserves`
+/// is configured for.
+pub const REFERENCE_TOTAL_SUPPLY: u64 = 1_000_000_000_000_000;
+
+/// Scale the configured virtual token reserves to a launch's total supply, so
+/// every launch opens at the same market cap and sells out at the same price
+/// multiple whatever its supply or decimals.
+/// Rounds DOWN (protocol-favorable: fewer virtual tokens, higher price).
+/// reserves = initial_virtual_token_reserves * total_supply / REFERENCE_TOTAL_SUPPLY
+pub fn scale_virtual_token_reserves(
+    initial_virtual_token_reserves: u64,
+    total_supply: u64,
+) -> Result<u64> {
+    let reserves = (initial_virtual_token_reserves as u128)
+        .checked_mul(total_supply as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / REFERENCE_TOTAL_SUPPLY as u128;
+    require!(reserves > 0, ErrorCode::InvalidTokenSupply);
+
+    u64::try_from(reserves).map_err(|_| error!(ErrorCode::MathOverflow))
+}
 
 /// Calculate tokens received for a given SOL input using constant product formula.
 /// k = virtual_sol * virtual_token (invariant)
@@ -73,6 +96,124 @@ pub fn calculate_sell_sol(
     Ok(sol_out as u64)
 }
 
+/// Calculate the SOL that must enter the curve to receive exactly `token_amount`
+/// tokens (inverse of calculate_buy_tokens).
+/// Rounds UP (protocol-favorable: buyer pays more SOL).
+/// sol_in = ceil(k / (virtual_token - token_amount)) - virtual_sol
+pub fn calculate_buy_sol_for_tokens(
+    virtual_sol_reserves: u64,
+    virtual_token_reserves: u64,
+    token_amount: u64,
+) -> Result<u64> {
+    if token_amount == 0 {
+        return Ok(0);
+    }
+
+    let virtual_sol = virtual_sol_reserves as u128;
+    let virtual_token = virtual_token_reserves as u128;
+    let tokens_out = token_amount as u128;
+
+    // Cannot drain the full virtual token reserve
+    require!(tokens_out < virtual_token, ErrorCode::InsufficientReserves);
+
+    let k = virtual_sol
+        .checked_mul(virtual_token)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let new_virtual_token = virtual_token
+        .checked_sub(tokens_out)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Ceiling division: (k + new_virtual_token - 1) / new_virtual_token
+    let new_virtual_sol = k
+        .checked_add(new_virtual_token - 1)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(new_virtual_token)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let sol_in = new_virtual_sol
+        .checked_sub(virtual_sol)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    u64::try_from(sol_in).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+/// Calculate the minimum tokens to sell for at least `sol_amount` gross SOL
+/// (inverse of calculate_sell_sol).
+/// Rounds UP (protocol-favorable: seller gives more tokens).
+/// Smallest t with floor(k / (virtual_token + t)) <= virtual_sol - sol_amount:
+/// t = floor(k / (virtual_sol - sol_amount + 1)) + 1 - virtual_token
+pub fn calculate_sell_tokens_for_sol(
+    virtual_sol_reserves: u64,
+    virtual_token_reserves: u64,
+    sol_amount: u64,
+) -> Result<u64> {
+    if sol_amount == 0 {
+        return Ok(0);
+    }
+
+    let virtual_sol = virtual_sol_reserves as u128;
+    let virtual_token = virtual_token_reserves as u128;
+    let sol_out = sol_amount as u128;
+
+    // Cannot withdraw the full virtual SOL reserve
+    require!(sol_out < virtual_sol, ErrorCode::InsufficientReserves);
+
+    let k = virtual_sol
+        .checked_mul(virtual_token)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let target_virtual_sol = virtual_sol
+        .checked_sub(sol_out)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let tokens_in = k
+        .checked_div(target_virtual_sol + 1)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_sub(virtual_token)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    u64::try_from(tokens_in).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+/// Calculate the smallest gross amount whose net after calculate_fee is at least
+/// `net_amount` (inverse of deducting a fee from an input).
+/// Rounds UP (protocol-favorable: payer covers the fee rounding).
+pub fn calculate_gross_for_net(net_amount: u64, fee_bps: u16) -> Result<u64> {
+    if net_amount == 0 {
+        return Ok(0);
+    }
+    if fee_bps == 0 {
+        return Ok(net_amount);
+    }
+
+    let bps = fee_bps as u128;
+    require!(bps < 10_000, ErrorCode::InvalidFeeConfiguration);
+
+    // Lower bound: gross = ceil(net * 10000 / (10000 - bps))
+    let denominator = 10_000 - bps;
+    let gross = (net_amount as u128)
+        .checked_mul(10_000)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(denominator - 1)
+        .ok_or(ErrorCode::MathOverflow)?
+        / denominator;
+    let mut gross = u64::try_from(gross).map_err(|_| error!(ErrorCode::MathOverflow))?;
+
+    // The fee itself rounds up, so the bound can fall short by a lamport or two
+    while gross
+        .checked_sub(calculate_fee(gross, fee_bps)?)
+        .ok_or(ErrorCode::MathOverflow)?
+        < net_amount
+    {
+        gross = gross.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    Ok(gross)
+}
+
 /// Calculate fee amount. Rounds UP (protocol-favorable: more fees collected).
 /// fee = ceil(amount * fee_bps / 10_000)
 pub fn calculate_fee(amount: u64, fee_bps: u16) -> Result<u64> {
@@ -95,6 +236,81 @@ pub fn calculate_fee(amount: u64, fee_bps: u16) -> Result<u64> {
     Ok(fee as u64)
 }
 
+/// Split a collected fee between platform and creator proportionally to their
+/// configured shares. The platform share rounds DOWN and the creator receives the
+/// remainder, so the odd lamport always goes to the creator and the two parts
+/// always sum to `total_fee`.
+/// platform = floor(total_fee * platform_fee_bps / (platform_fee_bps + creator_fee_bps))
+pub fn split_fee(
+    total_fee: u64,
+    platform_fee_bps: u16,
+    creator_fee_bps: u16,
+) -> Result<(u64, u64)> {
+    if total_fee == 0 {
+        return Ok((0, 0));
+    }
+
+    let share_sum = (platform_fee_bps as u128)
+        .checked_add(creator_fee_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let platform_fee = (total_fee as u128)
+        .checked_mul(platform_fee_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(share_sum)
+        .ok_or(ErrorCode::MathOverflow)? as u64;
+
+    let creator_fee = total_fee
+        .checked_sub(platform_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok((platform_fee, creator_fee))
+}
+
+/// Split `total` between basis-point shares that sum to 10000. Every part
+/// rounds DOWN and the last takes the remainder, so the parts always sum to
+/// `total`.
+pub fn split_by_bps(total: u64, shares_bps: &[u16]) -> Result<Vec<u64>> {
+    let mut parts = Vec::with_capacity(shares_bps.len());
+    let mut remaining = total;
+    for (i, &share_bps) in shares_bps.iter().enumerate() {
+        let part = if i + 1 == shares_bps.len() {
+            remaining
+        } else {
+            ((total as u128)
+                .checked_mul(share_bps as u128)
+                .ok_or(ErrorCode::MathOverflow)?
+                / 10_000) as u64
+        };
+        remaining = remaining.checked_sub(part).ok_or(ErrorCode::MathOverflow)?;
+        parts.push(part);
+    }
+    Ok(parts)
+}
+
+/// Split the vested part of an allocation between basis-point shares that sum
+/// to 10000. Every part rounds DOWN, so no share ever shrinks as more vests.
+/// The rounding remainder goes to the last share only once `total_vested`
+/// reaches `total_allocation`, when the parts sum to the whole allocation.
+pub fn vested_by_bps(
+    total_vested: u64,
+    total_allocation: u64,
+    shares_bps: &[u16],
+) -> Result<Vec<u64>> {
+    if total_vested == total_allocation {
+        return split_by_bps(total_vested, shares_bps);
+    }
+    shares_bps
+        .iter()
+        .map(|&share_bps| {
+            Ok(((total_vested as u128)
+                .checked_mul(share_bps as u128)
+                .ok_or(ErrorCode::MathOverflow)?
+                / 10_000) as u64)
+        })
+        .collect()
+}
+
 /// Calculate how many tokens a given SOL value is worth at current reserves.
 /// Used for burn-for-access pricing.
 /// Rounds UP (protocol-favorable: more tokens burned).
@@ -126,6 +342,401 @@ pub fn calculate_tokens_for_sol_value(
     Ok(tokens as u64)
 }
 
+// ------- Curve shapes -------
+
+/// Fixed-point scale for shaped-curve prices (lamports per token base unit).
+pub const PRICE_SCALE: u128 = 1_000_000_000_000;
+
+/// Segments in the piecewise-linear approximation of the exponential curve.
+/// Must stay a power of two: the per-segment ratio is taken by repeated sqrt.
+pub const EXPONENTIAL_SEGMENTS: u64 = 16;
+
+/// Price levels of the stepped curve.
+pub const STEPPED_LEVELS: u64 = 10;
+
+/// Everything the pricing functions need from a bonding curve.
+/// Constant product prices off the virtual reserves; the shaped kinds price off
+/// `tokens_sold` along a fixed path from `start_price` to `end_price` (both
+/// scaled by PRICE_SCALE) over `curve_supply` tokens.
+#[derive(Clone, Copy, Debug)]
+pub struct CurveParams {
+    pub kind: CurveKind,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub tokens_sold: u64,
+    pub curve_supply: u64,
+    pub start_price: u64,
+    pub end_price: u64,
+}
+
+/// Calculate tokens received for `sol_amount` entering the curve.
+/// Rounds DOWN (protocol-favorable: buyer gets fewer tokens).
+pub fn curve_buy_tokens(curve: &CurveParams, sol_amount: u64) -> Result<u64> {
+    if curve.kind == CurveKind::ConstantProduct {
+        return calculate_buy_tokens(
+            curve.virtual_sol_reserves,
+            curve.virtual_token_reserves,
+            sol_amount,
+        );
+    }
+    if sol_amount == 0 {
+        return Ok(0);
+    }
+    let shape = Shape::new(curve)?;
+
+    // Largest t with cost(sold + t) - cost(sold) <= sol_amount
+    let budget = shape
+        .cost(curve.tokens_sold)?
+        .checked_add(sol_amount as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let mut low = 0u64;
+    let mut high = curve
+        .curve_supply
+        .checked_sub(curve.tokens_sold)
+        .ok_or(ErrorCode::MathOverflow)?;
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        if shape.cost(curve.tokens_sold + mid)? <= budget {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    Ok(low)
+}
+
+/// Calculate gross SOL released for selling `token_amount` back to the curve.
+/// Rounds DOWN (protocol-favorable: seller gets less SOL).
+pub fn curve_sell_sol(curve: &CurveParams, token_amount: u64) -> Result<u64> {
+    if curve.kind == CurveKind::ConstantProduct {
+        return calculate_sell_sol(
+            curve.virtual_sol_reserves,
+            curve.virtual_token_reserves,
+            token_amount,
+        );
+    }
+    if token_amount == 0 {
+        return Ok(0);
+    }
+    let shape = Shape::new(curve)?;
+
+    // Only tokens bought from the curve are backed by its SOL
+    require!(
+        token_amount <= curve.tokens_sold,
+        ErrorCode::InsufficientReserves
+    );
+    let sol_out = shape
+        .cost(curve.tokens_sold)?
+        .checked_sub(shape.cost(curve.tokens_sold - token_amount)?)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    u64::try_from(sol_out).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+/// Calculate the SOL that must enter the curve for exactly `token_amount` tokens.
+/// Rounds UP (protocol-favorable: buyer pays more SOL).
+pub fn curve_buy_sol_for_tokens(curve: &CurveParams, token_amount: u64) -> Result<u64> {
+    if curve.kind == CurveKind::ConstantProduct {
+        return calculate_buy_sol_for_tokens(
+            curve.virtual_sol_reserves,
+            curve.virtual_token_reserves,
+            token_amount,
+        );
+    }
+    if token_amount == 0 {
+        return Ok(0);
+    }
+    let shape = Shape::new(curve)?;
+
+    let new_tokens_sold = curve
+        .tokens_sold
+        .checked_add(token_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        new_tokens_sold <= curve.curve_supply,
+        ErrorCode::InsufficientReserves
+    );
+    let sol_in = shape
+        .cost(new_tokens_sold)?
+        .checked_sub(shape.cost(curve.tokens_sold)?)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Never hand out tokens for free, even below one lamport of value
+    let sol_in = u64::try_from(sol_in).map_err(|_| error!(ErrorCode::MathOverflow))?;
+    Ok(sol_in.max(1))
+}
+
+/// Calculate the minimum tokens to sell for at least `sol_amount` gross SOL.
+/// Rounds UP (protocol-favorable: seller gives more tokens).
+pub fn curve_sell_tokens_for_sol(curve: &CurveParams, sol_amount: u64) -> Result<u64> {
+    if curve.kind == CurveKind::ConstantProduct {
+        return calculate_sell_tokens_for_sol(
+            curve.virtual_sol_reserves,
+            curve.virtual_token_reserves,
+            sol_amount,
+        );
+    }
+    if sol_amount == 0 {
+        return Ok(0);
+    }
+    let shape = Shape::new(curve)?;
+
+    // Smallest t with cost(sold) - cost(sold - t) >= sol_amount
+    let current_cost = shape.cost(curve.tokens_sold)?;
+    let target = current_cost
+        .checked_sub(sol_amount as u128)
+        .ok_or(ErrorCode::InsufficientReserves)?;
+    let mut low = 1u64;
+    let mut high = curve.tokens_sold;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if shape.cost(curve.tokens_sold - mid)? <= target {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    Ok(low)
+}
+
+/// Calculate how many tokens a given SOL value is worth at the current spot price.
+/// Used for burn-for-access pricing.
+/// Rounds UP (protocol-favorable: more tokens burned).
+pub fn curve_tokens_for_sol_value(curve: &CurveParams, sol_value: u64) -> Result<u64> {
+    if curve.kind == CurveKind::ConstantProduct {
+        return calculate_tokens_for_sol_value(
+            curve.virtual_sol_reserves,
+            curve.virtual_token_reserves,
+            sol_value,
+        );
+    }
+    if sol_value == 0 {
+        return Ok(0);
+    }
+    let shape = Shape::new(curve)?;
+
+    let price = shape.price(curve.tokens_sold)?;
+    require!(price > 0, ErrorCode::MathOverflow);
+
+    // Ceiling division: (sol_value * PRICE_SCALE + price - 1) / price
+    let tokens = (sol_value as u128)
+        .checked_mul(PRICE_SCALE)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(price - 1)
+        .ok_or(ErrorCode::MathOverflow)?
+        / price;
+
+    u64::try_from(tokens).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+/// Start and end prices (scaled by PRICE_SCALE) for a shaped curve, matched to
+/// the constant product curve over the same supply so every kind opens and
+/// sells out at the same price and differs only in the path between.
+/// start = v_sol / v_token, end = v_sol * v_token / (v_token - curve_supply)^2
+pub fn calculate_shaped_price_range(
+    virtual_sol_reserves: u64,
+    virtual_token_reserves: u64,
+    curve_supply: u64,
+) -> Result<(u64, u64)> {
+    let v_sol = virtual_sol_reserves as u128;
+    let v_token = virtual_token_reserves as u128;
+    require!(
+        v_token > curve_supply as u128,
+        ErrorCode::InvalidCurveConfiguration
+    );
+    let v_token_left = v_token - curve_supply as u128;
+
+    let start_price = v_sol
+        .checked_mul(PRICE_SCALE)
+        .ok_or(ErrorCode::MathOverflow)?
+        / v_token;
+    let end_price = start_price
+        .checked_mul(v_token)
+        .ok_or(ErrorCode::MathOverflow)?
+        / v_token_left;
+    let end_price = end_price
+        .checked_mul(v_token)
+        .ok_or(ErrorCode::MathOverflow)?
+        / v_token_left;
+
+    require!(
+        start_price > 0 && end_price > start_price,
+        ErrorCode::InvalidCurveConfiguration
+    );
+
+    Ok((
+        u64::try_from(start_price).map_err(|_| error!(ErrorCode::MathOverflow))?,
+        u64::try_from(end_price).map_err(|_| error!(ErrorCode::MathOverflow))?,
+    ))
+}
+
+fn segment_count(kind: CurveKind) -> u64 {
+    match kind {
+        CurveKind::ConstantProduct | CurveKind::Linear => 1,
+        CurveKind::Exponential => EXPONENTIAL_SEGMENTS,
+        CurveKind::Stepped => STEPPED_LEVELS,
+    }
+}
+
+/// Per-segment growth factor of the exponential curve, scaled by PRICE_SCALE:
+/// (end / start) ^ (1 / EXPONENTIAL_SEGMENTS), rounded down.
+fn exponential_ratio(curve: &CurveParams) -> Result<u128> {
+    let mut ratio = (curve.end_price as u128)
+        .checked_mul(PRICE_SCALE)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(curve.start_price as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let mut roots = EXPONENTIAL_SEGMENTS;
+    while roots > 1 {
+        ratio = isqrt(
+            ratio
+                .checked_mul(PRICE_SCALE)
+                .ok_or(ErrorCode::MathOverflow)?,
+        );
+        roots /= 2;
+    }
+    Ok(ratio)
+}
+
+/// A shaped curve with its exponential growth factor worked out once, since a
+/// single quote evaluates cost() dozens of times while searching.
+struct Shape<'a> {
+    curve: &'a CurveParams,
+    ratio: u128,
+}
+
+impl<'a> Shape<'a> {
+    fn new(curve: &'a CurveParams) -> Result<Self> {
+        let ratio = if curve.kind == CurveKind::Exponential {
+            exponential_ratio(curve)?
+        } else {
+            0
+        };
+        Ok(Self { curve, ratio })
+    }
+
+    /// Walk the segments, calling `visit(segment_start, segment_end,
+    /// price_at_start, price_at_end)` until it returns false. Prices are
+    /// non-decreasing, which keeps cost() monotonic.
+    fn for_each_segment<F>(&self, mut visit: F) -> Result<()>
+    where
+        F: FnMut(u128, u128, u128, u128) -> Result<bool>,
+    {
+        let curve = self.curve;
+        let supply = curve.curve_supply as u128;
+        let segments = segment_count(curve.kind) as u128;
+        let start = curve.start_price as u128;
+        let end = curve.end_price as u128;
+
+        let mut price = start;
+        for j in 0..segments {
+            let next_price = match curve.kind {
+                CurveKind::ConstantProduct | CurveKind::Linear => end,
+                // Force the last knot onto end_price so floor drift cannot accumulate
+                CurveKind::Exponential if j + 1 == segments => end,
+                CurveKind::Exponential => {
+                    price
+                        .checked_mul(self.ratio)
+                        .ok_or(ErrorCode::MathOverflow)?
+                        / PRICE_SCALE
+                }
+                CurveKind::Stepped => {
+                    end.checked_sub(start)
+                        .ok_or(ErrorCode::MathOverflow)?
+                        .checked_mul(j + 1)
+                        .ok_or(ErrorCode::MathOverflow)?
+                        / (segments - 1)
+                        + start
+                }
+            };
+            // Stepped levels are flat; the others interpolate linearly
+            let price_at_end = if curve.kind == CurveKind::Stepped {
+                price
+            } else {
+                next_price
+            };
+
+            let segment_start = supply * j / segments;
+            let segment_end = supply * (j + 1) / segments;
+            if !visit(segment_start, segment_end, price, price_at_end)? {
+                break;
+            }
+            price = next_price;
+        }
+        Ok(())
+    }
+
+    /// Cumulative SOL (lamports, rounded down) to buy the first `tokens`.
+    /// Buys and sells both settle as differences of this one function, so any
+    /// sequence of trades telescopes and round trips can never extract SOL.
+    fn cost(&self, tokens: u64) -> Result<u128> {
+        require!(
+            tokens <= self.curve.curve_supply,
+            ErrorCode::InsufficientReserves
+        );
+        let tokens = tokens as u128;
+
+        let mut total: u128 = 0;
+        self.for_each_segment(|segment_start, segment_end, price_start, price_end| {
+            if tokens <= segment_start {
+                return Ok(false);
+            }
+            let length = segment_end - segment_start;
+            let x = tokens.min(segment_end) - segment_start;
+
+            // x * price_start + (price_end - price_start) * x^2 / (2 * length)
+            let flat = x.checked_mul(price_start).ok_or(ErrorCode::MathOverflow)?;
+            let rise = (price_end - price_start)
+                .checked_mul(x)
+                .ok_or(ErrorCode::MathOverflow)?
+                / length
+                * x
+                / 2;
+            total = total
+                .checked_add(flat)
+                .and_then(|t| t.checked_add(rise))
+                .ok_or(ErrorCode::MathOverflow)?;
+            Ok(true)
+        })?;
+
+        Ok(total / PRICE_SCALE)
+    }
+
+    /// Spot price (scaled by PRICE_SCALE) after `tokens` have been sold.
+    fn price(&self, tokens: u64) -> Result<u128> {
+        let tokens = (tokens as u128).min(self.curve.curve_supply as u128);
+
+        let mut price = self.curve.end_price as u128;
+        self.for_each_segment(|segment_start, segment_end, price_start, price_end| {
+            if tokens >= segment_end {
+                return Ok(true);
+            }
+            price = price_start
+                + (price_end - price_start) * (tokens - segment_start)
+                    / (segment_end - segment_start);
+            Ok(false)
+        })?;
+
+        Ok(price)
+    }
+}
+
+/// Integer square root, rounded down.
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x / 2 + (x & 1);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,6 +796,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::manual_abs_diff)]
     fn test_buy_sell_round_trip() {
         // Buy tokens, then sell the same tokens back.
         // Due to integer floor division, the round-trip may have tiny rounding variance
@@ -235,6 +847,99 @@ mod tests {
         assert_eq!(fee_half, 25_000_000);
     }
 
+    #[test]
+    fn test_buy_sol_for_tokens_inverts_buy() {
+        // Paying the quoted SOL yields at least the requested tokens, one lamport less does not
+        for token_amount in [1u64, 1_000_000, 34_000_000_000_000, 500_000_000_000_000] {
+            let sol_in = calculate_buy_sol_for_tokens(
+                VIRTUAL_SOL_RESERVES,
+                VIRTUAL_TOKEN_RESERVES,
+                token_amount,
+            )
+            .unwrap();
+
+            let tokens =
+                calculate_buy_tokens(VIRTUAL_SOL_RESERVES, VIRTUAL_TOKEN_RESERVES, sol_in).unwrap();
+            assert!(tokens >= token_amount);
+
+            let tokens_short =
+                calculate_buy_tokens(VIRTUAL_SOL_RESERVES, VIRTUAL_TOKEN_RESERVES, sol_in - 1)
+                    .unwrap();
+            assert!(tokens_short < token_amount);
+        }
+    }
+
+    #[test]
+    fn test_buy_sol_for_tokens_edge_cases() {
+        assert_eq!(
+            calculate_buy_sol_for_tokens(VIRTUAL_SOL_RESERVES, VIRTUAL_TOKEN_RESERVES, 0).unwrap(),
+            0
+        );
+        // Cannot buy the entire virtual reserve
+        assert!(calculate_buy_sol_for_tokens(
+            VIRTUAL_SOL_RESERVES,
+            VIRTUAL_TOKEN_RESERVES,
+            VIRTUAL_TOKEN_RESERVES
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_sell_tokens_for_sol_inverts_sell() {
+        // Selling the quoted tokens yields at least the requested SOL, one token less does not
+        for sol_amount in [1u64, 1_000, 950_000_000, 10_000_000_000] {
+            let tokens = calculate_sell_tokens_for_sol(
+                VIRTUAL_SOL_RESERVES,
+                VIRTUAL_TOKEN_RESERVES,
+                sol_amount,
+            )
+            .unwrap();
+
+            let sol =
+                calculate_sell_sol(VIRTUAL_SOL_RESERVES, VIRTUAL_TOKEN_RESERVES, tokens).unwrap();
+            assert!(sol >= sol_amount);
+
+            let sol_short =
+                calculate_sell_sol(VIRTUAL_SOL_RESERVES, VIRTUAL_TOKEN_RESERVES, tokens - 1)
+                    .unwrap();
+            assert!(sol_short < sol_amount);
+        }
+    }
+
+    #[test]
+    fn test_sell_tokens_for_sol_edge_cases() {
+        assert_eq!(
+            calculate_sell_tokens_for_sol(VIRTUAL_SOL_RESERVES, VIRTUAL_TOKEN_RESERVES, 0).unwrap(),
+            0
+        );
+        // Cannot withdraw the entire virtual SOL reserve
+        assert!(calculate_sell_tokens_for_sol(
+            VIRTUAL_SOL_RESERVES,
+            VIRTUAL_TOKEN_RESERVES,
+            VIRTUAL_SOL_RESERVES
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_gross_for_net() {
+        for net in [1u64, 7, 999, 950_000_000, 123_456_789_012] {
+            let gross = calculate_gross_for_net(net, 500).unwrap();
+            assert!(gross - calculate_fee(gross, 500).unwrap() >= net);
+            // Minimal: one lamport less no longer covers the net amount
+            assert!(gross - 1 - calculate_fee(gross - 1, 500).unwrap() < net);
+        }
+
+        // 0.95 SOL net at 5% fee requires exactly 1 SOL gross
+        assert_eq!(
+            calculate_gross_for_net(950_000_000, 500).unwrap(),
+            1_000_000_000
+        );
+        assert_eq!(calculate_gross_for_net(0, 500).unwrap(), 0);
+        assert_eq!(calculate_gross_for_net(1_000, 0).unwrap(), 1_000);
+        assert!(calculate_gross_for_net(1_000, 10_000).is_err());
+    }
+
     #[test]
     fn test_fee_zero_inputs() {
         assert_eq!(calculate_fee(0, 500).unwrap(), 0);
@@ -242,6 +947,49 @@ mod tests {
         assert_eq!(calculate_fee(0, 0).unwrap(), 0);
     }
 
+    #[test]
+    fn test_split_fee_even_shares() {
+        // 250/250 split: odd lamport goes to the creator
+        assert_eq!(
+            split_fee(50_000_000, 250, 250).unwrap(),
+            (25_000_000, 25_000_000)
+        );
+        assert_eq!(split_fee(101, 250, 250).unwrap(), (50, 51));
+    }
+
+    #[test]
+    fn test_split_fee_uneven_shares() {
+        // 3%/2% split of a 5% fee
+        assert_eq!(
+            split_fee(50_000_000, 300, 200).unwrap(),
+            (30_000_000, 20_000_000)
+        );
+
+        // floor(7 * 300 / 500) = 4, remainder 3 to creator
+        assert_eq!(split_fee(7, 300, 200).unwrap(), (4, 3));
+    }
+
+    #[test]
+    fn test_split_fee_single_recipient() {
+        assert_eq!(split_fee(1_000, 500, 0).unwrap(), (1_000, 0));
+        assert_eq!(split_fee(1_000, 0, 500).unwrap(), (0, 1_000));
+    }
+
+    #[test]
+    fn test_split_fee_zero_inputs() {
+        assert_eq!(split_fee(0, 250, 250).unwrap(), (0, 0));
+        assert_eq!(split_fee(0, 0, 0).unwrap(), (0, 0));
+        assert!(split_fee(1, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_split_fee_sums_to_total() {
+        for total in [1u64, 2, 3, 99, 12_345, u64::MAX] {
+            let (platform, creator) = split_fee(total, 333, 167).unwrap();
+            assert_eq!(platform + creator, total);
+        }
+    }
+
     #[test]
     fn test_buy_zero_amount() {
         let tokens = calculate_buy_tokens(VIRTUAL_SOL_RESERVES, VIRTUAL_TOKEN_RESERVES, 0).unwrap();
@@ -318,4 +1066,320 @@ mod tests {
             tokens_whole
         );
     }
+
+    // 90% of a 1B supply sits on the curve at launch
+    const CURVE_SUPPLY: u64 = 900_000_000_000_000;
+
+    fn shaped_curve(kind: CurveKind, tokens_sold: u64) -> CurveParams {
+        let (start_price, end_price) = calculate_shaped_price_range(
+            VIRTUAL_SOL_RESERVES,
+            VIRTUAL_TOKEN_RESERVES,
+            CURVE_SUPPLY,
+        )
+        .unwrap();
+        CurveParams {
+            kind,
+            virtual_sol_reserves: VIRTUAL_SOL_RESERVES,
+            virtual_token_reserves: VIRTUAL_TOKEN_RESERVES,
+            tokens_sold,
+            curve_supply: CURVE_SUPPLY,
+            start_price,
+            end_price,
+        }
+    }
+
+    const SHAPED_KINDS: [CurveKind; 3] = [
+        CurveKind::Linear,
+        CurveKind::Exponential,
+        CurveKind::Stepped,
+    ];
+
+    #[test]
+    fn test_shaped_price_range_matches_constant_product() {
+        let (start, end) = calculate_shaped_price_range(
+            VIRTUAL_SOL_RESERVES,
+            VIRTUAL_TOKEN_RESERVES,
+            CURVE_SUPPLY,
+        )
+        .unwrap();
+        // 30 SOL / 1073M tokens ~= 27.96 lamports per whole token
+        assert_eq!(start, 27_958_993);
+        // (1073 / 173)^2 ~= 38.47x the opening price
+        assert!(end / start == 38, "end/start = {}", end / start);
+
+        // The curve must not be able to sell out its virtual reserve
+        assert!(calculate_shaped_price_range(
+            VIRTUAL_SOL_RESERVES,
+            VIRTUAL_TOKEN_RESERVES,
+            VIRTUAL_TOKEN_RESERVES
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_constant_product_dispatch_matches_direct_math() {
+        let curve = CurveParams {
+            kind: CurveKind::ConstantProduct,
+            ..shaped_curve(CurveKind::Linear, 0)
+        };
+        assert_eq!(
+            curve_buy_tokens(&curve, 1_000_000_000).unwrap(),
+            calculate_buy_tokens(VIRTUAL_SOL_RESERVES, VIRTUAL_TOKEN_RESERVES, 1_000_000_000)
+                .unwrap()
+        );
+        assert_eq!(
+            curve_sell_sol(&curve, 34_000_000_000_000).unwrap(),
+            calculate_sell_sol(
+                VIRTUAL_SOL_RESERVES,
+                VIRTUAL_TOKEN_RESERVES,
+                34_000_000_000_000
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_shaped_price_increases_with_supply_sold() {
+        for kind in SHAPED_KINDS {
+            let curve = shaped_curve(kind, 0);
+            let mut last = 0;
+            for sold in (0..=CURVE_SUPPLY).step_by(CURVE_SUPPLY as usize / 50) {
+                let price = Shape::new(&curve).unwrap().price(sold).unwrap();
+                assert!(price >= last, "{:?} price fell at {}", kind, sold);
+                last = price;
+            }
+            assert_eq!(
+                Shape::new(&curve).unwrap().price(0).unwrap(),
+                curve.start_price as u128
+            );
+            assert_eq!(
+                Shape::new(&curve).unwrap().price(CURVE_SUPPLY).unwrap(),
+                curve.end_price as u128
+            );
+        }
+    }
+
+    #[test]
+    fn test_shaped_buy_sell_round_trip_never_profits() {
+        for kind in SHAPED_KINDS {
+            for sold in [0u64, 123_456_789_000, 400_000_000_000_000] {
+                for sol_in in [1u64, 777, 1_000_000_000, 25_000_000_000] {
+                    let curve = shaped_curve(kind, sold);
+                    let tokens = curve_buy_tokens(&curve, sol_in).unwrap();
+                    let after = shaped_curve(kind, sold + tokens);
+                    let sol_out = curve_sell_sol(&after, tokens).unwrap();
+                    assert!(
+                        sol_out <= sol_in,
+                        "{:?}: {} in, {} out",
+                        kind,
+                        sol_in,
+                        sol_out
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shaped_trades_are_path_independent() {
+        for kind in SHAPED_KINDS {
+            let start = shaped_curve(kind, 10_000_000_000_000);
+            let a = 50_000_000_000_000;
+            let b = 70_000_000_000_000;
+
+            let cost_a = curve_buy_sol_for_tokens(&start, a).unwrap();
+            let cost_b =
+                curve_buy_sol_for_tokens(&shaped_curve(kind, start.tokens_sold + a), b).unwrap();
+            let cost_ab = curve_buy_sol_for_tokens(&start, a + b).unwrap();
+            assert_eq!(cost_a + cost_b, cost_ab, "{:?}", kind);
+        }
+    }
+
+    #[test]
+    fn test_shaped_buy_sol_for_tokens_inverts_buy() {
+        for kind in SHAPED_KINDS {
+            let curve = shaped_curve(kind, 200_000_000_000_000);
+            for token_amount in [1u64, 1_000_000, 34_000_000_000_000] {
+                let sol_in = curve_buy_sol_for_tokens(&curve, token_amount).unwrap();
+                assert!(curve_buy_tokens(&curve, sol_in).unwrap() >= token_amount);
+            }
+            // Cannot buy past the curve supply
+            assert!(curve_buy_sol_for_tokens(&curve, CURVE_SUPPLY).is_err());
+        }
+    }
+
+    #[test]
+    fn test_shaped_sell_tokens_for_sol_inverts_sell() {
+        for kind in SHAPED_KINDS {
+            let curve = shaped_curve(kind, 300_000_000_000_000);
+            for sol_amount in [1u64, 1_000, 950_000_000] {
+                let tokens = curve_sell_tokens_for_sol(&curve, sol_amount).unwrap();
+                assert!(curve_sell_sol(&curve, tokens).unwrap() >= sol_amount);
+                assert!(curve_sell_sol(&curve, tokens - 1).unwrap() < sol_amount);
+            }
+            // Cannot sell back more than was bought
+            assert!(curve_sell_sol(&curve, curve.tokens_sold + 1).is_err());
+        }
+    }
+
+    #[test]
+    fn test_shaped_tokens_for_sol_value_rounds_up() {
+        for kind in SHAPED_KINDS {
+            let curve = shaped_curve(kind, 0);
+            // At the opening price 1 SOL buys ~35.77M tokens on every shape
+            let tokens = curve_tokens_for_sol_value(&curve, 1_000_000_000).unwrap();
+            let price = Shape::new(&curve).unwrap().price(0).unwrap();
+            assert!(tokens as u128 * price >= 1_000_000_000 * PRICE_SCALE);
+            assert!((tokens as u128 - 1) * price < 1_000_000_000 * PRICE_SCALE);
+            assert_eq!(tokens / 1_000_000_000_000, 35);
+        }
+    }
+
+    #[test]
+    fn test_stepped_curve_is_flat_within_a_level() {
+        let curve = shaped_curve(CurveKind::Stepped, 0);
+        let level = CURVE_SUPPLY / STEPPED_LEVELS;
+        assert_eq!(
+            Shape::new(&curve).unwrap().price(1).unwrap(),
+            Shape::new(&curve).unwrap().price(level - 1).unwrap()
+        );
+        assert!(
+            Shape::new(&curve).unwrap().price(level).unwrap()
+                > Shape::new(&curve).unwrap().price(level - 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_scale_virtual_token_reserves() {
+        // Reference launch keeps the configured reserves
+        assert_eq!(
+            scale_virtual_token_reserves(VIRTUAL_TOKEN_RESERVES, REFERENCE_TOTAL_SUPPLY).unwrap(),
+            VIRTUAL_TOKEN_RESERVES
+        );
+        // 10M tokens with 6 decimals
+        assert_eq!(
+            scale_virtual_token_reserves(VIRTUAL_TOKEN_RESERVES, 10_000_000_000_000).unwrap(),
+            10_730_000_000_000
+        );
+        // 1B tokens with 9 decimals
+        assert_eq!(
+            scale_virtual_token_reserves(VIRTUAL_TOKEN_RESERVES, 1_000_000_000_000_000_000)
+                .unwrap(),
+            1_073_000_000_000_000_000
+        );
+        // A supply too small to leave any virtual reserve
+        assert!(scale_virtual_token_reserves(VIRTUAL_TOKEN_RESERVES, 0).is_err());
+    }
+
+    #[test]
+    fn test_small_supply_buy_scales_proportionally() {
+        // 10M-supply launch: the same SOL buys the same share of supply
+        let supply = 10_000_000_000_000;
+        let virtual_token = scale_virtual_token_reserves(VIRTUAL_TOKEN_RESERVES, supply).unwrap();
+
+        let reference =
+            calculate_buy_tokens(VIRTUAL_SOL_RESERVES, VIRTUAL_TOKEN_RESERVES, 1_000_000_000)
+                .unwrap();
+        let small =
+            calculate_buy_tokens(VIRTUAL_SOL_RESERVES, virtual_token, 1_000_000_000).unwrap();
+        assert!(small.abs_diff(reference / 100) <= 1);
+
+        // Round trip stays within the same 1 lamport of rounding as the reference
+        let sol_out = calculate_sell_sol(
+            VIRTUAL_SOL_RESERVES + 1_000_000_000,
+            virtual_token - small,
+            small,
+        )
+        .unwrap();
+        assert!(sol_out.abs_diff(1_000_000_000) <= 1);
+
+        // Burn pricing keeps the same SOL value per share of supply
+        let tokens =
+            calculate_tokens_for_sol_value(VIRTUAL_SOL_RESERVES, virtual_token, 1_000_000_000)
+                .unwrap();
+        assert_eq!(tokens / 1_000_000, 357_666);
+    }
+
+    #[test]
+    fn test_large_supply_shaped_curves_hold_guarantees() {
+        // 1B tokens with 9 decimals: 1000x the reference base units
+        let supply: u64 = 1_000_000_000_000_000_000;
+        let curve_supply = supply / 10 * 9;
+        let virtual_token = scale_virtual_token_reserves(VIRTUAL_TOKEN_RESERVES, supply).unwrap();
+        let (start_price, end_price) =
+            calculate_shaped_price_range(VIRTUAL_SOL_RESERVES, virtual_token, curve_supply)
+                .unwrap();
+        assert!(start_price > 0 && end_price > start_price);
+
+        for kind in SHAPED_KINDS {
+            let curve = CurveParams {
+                kind,
+                virtual_sol_reserves: VIRTUAL_SOL_RESERVES,
+                virtual_token_reserves: virtual_token,
+                tokens_sold: 0,
+                curve_supply,
+                start_price,
+                end_price,
+            };
+            let tokens = curve_buy_tokens(&curve, 5_000_000_000).unwrap();
+            assert!(tokens > 0);
+            let after = CurveParams {
+                tokens_sold: tokens,
+                ..curve
+            };
+            assert!(curve_sell_sol(&after, tokens).unwrap() <= 5_000_000_000);
+
+            // Buying out the whole curve stays within u128 math
+            assert!(curve_buy_sol_for_tokens(&curve, curve_supply).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_split_by_bps_remainder_to_last() {
+        let parts = split_by_bps(1_000_003, &[5_000, 3_333, 1_667]).unwrap();
+        assert_eq!(parts[0], 500_001);
+        assert_eq!(parts[1], 333_300);
+        assert_eq!(parts[2], 1_000_003 - 500_001 - 333_300);
+        assert_eq!(parts.iter().sum::<u64>(), 1_000_003);
+    }
+
+    #[test]
+    fn test_split_by_bps_single_share_and_zero_total() {
+        assert_eq!(split_by_bps(u64::MAX, &[10_000]).unwrap(), vec![u64::MAX]);
+        assert_eq!(split_by_bps(0, &[4_000, 6_000]).unwrap(), vec![0, 0]);
+        assert!(split_by_bps(u64::MAX, &[5_000, 5_000]).is_ok());
+    }
+
+    #[test]
+    fn test_vested_by_bps_monotonic_per_share() {
+        let shares = [3_333, 3_333, 3_334];
+        let total_allocation = 10;
+
+        // Mid-vesting every share rounds down; the last no longer takes the remainder
+        assert_eq!(
+            vested_by_bps(3, total_allocation, &shares).unwrap(),
+            vec![0, 0, 1]
+        );
+        assert_eq!(
+            vested_by_bps(4, total_allocation, &shares).unwrap(),
+            vec![1, 1, 1]
+        );
+
+        let mut previous = vec![0; shares.len()];
+        for total_vested in 0..=total_allocation {
+            let parts = vested_by_bps(total_vested, total_allocation, &shares).unwrap();
+            for (part, prev) in parts.iter().zip(&previous) {
+                assert!(
+                    part >= prev,
+                    "share shrank at total_vested = {}",
+                    total_vested
+                );
+            }
+            assert!(parts.iter().sum::<u64>() <= total_vested);
+            previous = parts;
+        }
+
+        // Fully vested, the remainder goes to the last share
+        assert_eq!(previous, vec![3, 3, 4]);
+    }
 }
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Creator-defined access pass tier (e.g. 24 hours, 30 days).
+#[account]
+#[derive(InitSpace)]
+pub struct PassTier {
+    /// Creator token the pass is paid in
+    pub token_mint: Pubkey,
+    /// Tier index chosen by the creator
+    pub tier: u8,
+    /// SOL-denominated burn cost of one pass (0 = tier disabled)
+    pub burn_sol_price: u64,
+    /// Access duration granted per purchase in seconds
+    pub duration_seconds: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Time-limited access for a viewer to a creator's premium content. Buying a
+/// pass while one is active extends it from the current expiry.
+#[account]
+#[derive(InitSpace)]
+pub struct AccessPass {
+    /// Viewer holding the pass
+    pub viewer: Pubkey,
+    /// Creator token the pass grants access for
+    pub token_mint: Pubkey,
+    /// Unix timestamp the pass expires at
+    pub expires_at: i64,
+    /// Tier of the most recent purchase
+    pub last_tier: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Proof that `viewer` holds an unlock of `content_id`, burned either by the
+/// viewer or gifted by another wallet. One receipt per (viewer, mint, content),
+/// so a second burn or gift for the same content fails at account creation.
+#[account]
+#[derive(InitSpace)]
+pub struct AccessReceipt {
+    /// Viewer who burned tokens
+    pub viewer: Pubkey,
+    /// Creator token that was burned
+    pub token_mint: Pubkey,
+    /// Off-chain content identifier (e.g. hash of the post ID)
+    pub content_id: [u8; 32],
+    /// Tokens destroyed by the burn
+    pub tokens_burned: u64,
+    /// SOL-denominated price the burn was valued at
+    pub sol_value: u64,
+    /// Unix timestamp of the burn
+    pub timestamp: i64,
+    /// Wallet whose tokens were burned (the viewer unless the unlock was gifted)
+    pub payer: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
@@ -1,5 +1,43 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ErrorCode;
+use crate::math::CurveParams;
+
+/// Per-curve trading switch controlled by the platform authority.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum TradingStatus {
+    /// Buys, sells and burns allowed
+    Active,
+    /// Only sells allowed, so holders can exit a delisted creator
+    SellsOnly,
+    /// No trading or burning
+    Halted,
+}
+
+/// Price curve shape chosen at launch. The platform limits the choice through
+/// `GlobalConfig::allowed_curve_kinds`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum CurveKind {
+    /// Pump.fun-style x * y = k on virtual reserves
+    ConstantProduct,
+    /// Price rises linearly with tokens sold
+    Linear,
+    /// Price grows by a constant factor per segment of tokens sold
+    Exponential,
+    /// Flat price levels that jump at fixed supply steps
+    Stepped,
+}
+
+impl CurveKind {
+    /// Bitmask covering every kind, for validating `allowed_curve_kinds`
+    pub const ALL_MASK: u8 = 0b1111;
+
+    /// This kind's bit in `GlobalConfig::allowed_curve_kinds`
+    pub fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct BondingCurve {
@@ -25,4 +63,84 @@ pub struct BondingCurve {
     pub creator_fees_accrued: u64,
     /// PDA bump seed
     pub bump: u8,
+    // Fields below were appended after the original layout; see `LEGACY_SPACE`
+    /// Timestamp of the last update_burn_price call (0 = never updated)
+    pub burn_price_updated_at: i64,
+    /// Trading status set by the platform authority
+    pub trading_status: TradingStatus,
+    /// End of the anti-sniping launch window (0 = no window)
+    pub launch_window_ends_at: i64,
+    /// Per-wallet token cap inside the launch window (0 = no cap)
+    pub max_tokens_per_wallet: u64,
+    /// Per-transaction SOL cap inside the launch window (0 = no cap)
+    pub max_sol_per_tx: u64,
+    /// Price curve shape
+    pub curve_kind: CurveKind,
+    /// Tokens bought from the curve and not yet sold back
+    pub tokens_sold: u64,
+    /// Tokens the curve held for sale at launch
+    pub curve_supply: u64,
+    /// Shaped-curve price at zero tokens sold, scaled by math::PRICE_SCALE (0 for constant product)
+    pub curve_start_price: u64,
+    /// Shaped-curve price once curve_supply is sold, scaled by math::PRICE_SCALE (0 for constant product)
+    pub curve_end_price: u64,
+}
+
+impl BondingCurve {
+    /// Account size (with discriminator) of the original layout, which ended at
+    /// `bump`. Curves of this size are grown in place by `migrate_curve`.
+    pub const LEGACY_SPACE: usize = 8 + 32 * 2 + 8 * 8 + 1;
+
+    /// Pricing inputs for the math::curve_* functions
+    pub fn curve_params(&self) -> CurveParams {
+        CurveParams {
+            kind: self.curve_kind,
+            virtual_sol_reserves: self.virtual_sol_reserves,
+            virtual_token_reserves: self.virtual_token_reserves,
+            tokens_sold: self.tokens_sold,
+            curve_supply: self.curve_supply,
+            start_price: self.curve_start_price,
+            end_price: self.curve_end_price,
+        }
+    }
+
+    /// Apply a settled buy to the curve: SOL after fees enters the reserves,
+    /// tokens leave them and fees accrue. Transfers are the caller's job.
+    pub fn apply_buy(
+        &mut self,
+        sol_into_curve: u64,
+        tokens_out: u64,
+        platform_fee: u64,
+        creator_fee: u64,
+    ) -> Result<()> {
+        self.virtual_sol_reserves = self
+            .virtual_sol_reserves
+            .checked_add(sol_into_curve)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.virtual_token_reserves = self
+            .virtual_token_reserves
+            .checked_sub(tokens_out)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.real_sol_reserves = self
+            .real_sol_reserves
+            .checked_add(sol_into_curve)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.real_token_reserves = self
+            .real_token_reserves
+            .checked_sub(tokens_out)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.tokens_sold = self
+            .tokens_sold
+            .checked_add(tokens_out)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.platform_fees_accrued = self
+            .platform_fees_accrued
+            .checked_add(platform_fee)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.creator_fees_accrued = self
+            .creator_fees_accrued
+            .checked_add(creator_fee)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
 }
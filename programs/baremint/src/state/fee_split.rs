@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::WalletShare;
+
+/// Most recipients one fee split can pay
+pub const MAX_FEE_SPLIT_RECIPIENTS: usize = 5;
+
+/// Creator fee revenue split for one curve. Once it exists, creator fees leave
+/// the curve only through distribute_creator_fees.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeSplit {
+    /// Token mint of the curve this split pays out for
+    pub token_mint: Pubkey,
+    /// Recipients paid by distribute_creator_fees
+    #[max_len(MAX_FEE_SPLIT_RECIPIENTS)]
+    pub recipients: Vec<WalletShare>,
+    /// Replacement split waiting out the timelock (empty = none)
+    #[max_len(MAX_FEE_SPLIT_RECIPIENTS)]
+    pub pending_recipients: Vec<WalletShare>,
+    /// When the pending split may be applied (0 = none pending)
+    pub pending_effective_at: i64,
+    /// Per-recipient payouts held back because paying them would have left the
+    /// recipient below rent exemption. Still counted in the curve's
+    /// `creator_fees_accrued` and paid on a later distribution.
+    #[max_len(MAX_FEE_SPLIT_RECIPIENTS)]
+    pub carried_fees: Vec<u64>,
+    /// PDA bump seed
+    pub bump: u8,
+}
@@ -1,5 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ErrorCode;
+use crate::state::CurveKind;
+
+/// Upper bound for `max_token_decimals`, matching native SOL
+pub const MAX_TOKEN_DECIMALS: u8 = 9;
+
 #[account]
 #[derive(InitSpace)]
 pub struct GlobalConfig {
@@ -11,20 +17,243 @@ pub struct GlobalConfig {
     pub platform_fee_bps: u16,
     /// Creator's share of fees in basis points (e.g., 250 = 2.5%)
     pub creator_fee_bps: u16,
-    /// Initial virtual token reserves for new bonding curves
+    /// Initial virtual token reserves for a launch of math::REFERENCE_TOTAL_SUPPLY,
+    /// scaled in proportion to each launch's total supply
     pub initial_virtual_token_reserves: u64,
     /// Initial virtual SOL reserves for new bonding curves
     pub initial_virtual_sol_reserves: u64,
-    /// Vesting cliff duration in seconds (default: 2,592,000 = 30 days)
+    /// Default vesting cliff for launches that do not choose one (default: 2,592,000 = 30 days)
     pub vesting_cliff_seconds: i64,
-    /// Vesting total duration in seconds (default: 5,184,000 = 60 days)
+    /// Default vesting duration for launches that do not choose one (default: 5,184,000 = 60 days)
     pub vesting_duration_seconds: i64,
-    /// Minimum interval between vesting claims in seconds (default: 604,800 = 7 days)
+    /// Default interval between vesting claims (default: 604,800 = 7 days)
     pub vesting_claim_interval_seconds: i64,
     /// Cooldown between token launches per creator in seconds (default: 7,776,000 = 90 days)
     pub launch_cooldown_seconds: i64,
-    /// Creator token allocation in basis points (default: 1000 = 10%)
+    /// Default creator token allocation in basis points (default: 1000 = 10%)
     pub creator_allocation_bps: u16,
     /// PDA bump seed
     pub bump: u8,
+    // Fields below were appended after the original layout; see `LEGACY_SPACE`
+    /// Authority proposed via propose_authority, awaiting acceptance (default = none)
+    pub pending_authority: Pubkey,
+    /// Emergency pause: blocks launches, trades and burns on every curve
+    pub paused: bool,
+    /// Lowest non-zero burn_sol_price a creator may set (0 = no minimum)
+    pub min_burn_sol_price: u64,
+    /// Highest burn_sol_price a creator may set (0 = no maximum)
+    pub max_burn_sol_price: u64,
+    /// Minimum seconds between burn price updates on a curve (0 = no limit)
+    pub burn_price_update_cooldown_seconds: i64,
+    /// Lock period after a stake before tokens can be unstaked (default: 604,800 = 7 days)
+    pub unstake_cooldown_seconds: i64,
+    /// Max share of total supply a creator may buy at launch, in basis points (default: 500 = 5%)
+    pub max_initial_buy_bps: u16,
+    /// Anti-sniping window after launch in seconds (0 = disabled)
+    pub launch_window_seconds: i64,
+    /// Max tokens one wallet may buy inside the launch window (0 = no cap)
+    pub launch_max_tokens_per_wallet: u64,
+    /// Max SOL per buy transaction inside the launch window (0 = no cap)
+    pub launch_max_sol_per_tx: u64,
+    /// Referrer's cut of the platform fee share in basis points (0 = referrals off)
+    pub referral_fee_bps: u16,
+    /// Bitmask of CurveKind values creators may launch with (see CurveKind::mask)
+    pub allowed_curve_kinds: u8,
+    /// Smallest total supply (base units) a launch may mint (default: 1M tokens at 6 decimals)
+    pub min_total_supply: u64,
+    /// Largest total supply (base units) a launch may mint (default: 1B tokens at 9 decimals)
+    pub max_total_supply: u64,
+    /// Fewest mint decimals a launch may use (default: 0)
+    pub min_token_decimals: u8,
+    /// Most mint decimals a launch may use (default: 9)
+    pub max_token_decimals: u8,
+    /// Largest creator allocation a launch may choose, in basis points (default: 1000 = 10%)
+    pub max_creator_allocation_bps: u16,
+    /// Shortest vesting cliff a launch may choose (default: 2,592,000 = 30 days)
+    pub min_vesting_cliff_seconds: i64,
+    /// Shortest vesting duration a launch may choose (default: 5,184,000 = 60 days)
+    pub min_vesting_duration_seconds: i64,
+    /// Delay before a change to an existing fee split takes effect (default: 259,200 = 3 days)
+    pub fee_split_timelock_seconds: i64,
+}
+
+impl GlobalConfig {
+    /// Account size (with discriminator) of the original layout, which ended at
+    /// `bump`. Configs of this size are grown in place by `migrate_config`.
+    pub const LEGACY_SPACE: usize = 8 + 32 + 2 * 3 + 8 * 2 + 8 * 4 + 2 + 1;
+
+    /// Defaults for the fields appended after the original layout. Shared by
+    /// `initialize` and `migrate_config`.
+    pub fn set_appended_defaults(&mut self) {
+        self.pending_authority = Pubkey::default();
+        self.paused = false;
+        self.min_burn_sol_price = 0;
+        self.max_burn_sol_price = 0;
+        self.burn_price_update_cooldown_seconds = 0;
+        self.unstake_cooldown_seconds = 604_800; // 7 days
+        self.max_initial_buy_bps = 500; // 5%
+        self.launch_window_seconds = 0;
+        self.launch_max_tokens_per_wallet = 0;
+        self.launch_max_sol_per_tx = 0;
+        self.referral_fee_bps = 0;
+        self.allowed_curve_kinds = CurveKind::ConstantProduct.mask();
+        self.min_total_supply = 1_000_000_000_000; // 1M with 6 decimals
+        self.max_total_supply = 1_000_000_000_000_000_000; // 1B with 9 decimals
+        self.min_token_decimals = 0;
+        self.max_token_decimals = 9;
+        self.max_creator_allocation_bps = 1000; // 10%
+        self.min_vesting_cliff_seconds = 2_592_000; // 30 days
+        self.min_vesting_duration_seconds = 5_184_000; // 60 days
+        self.fee_split_timelock_seconds = 259_200; // 3 days
+    }
+
+    /// Validate the economic parameters. Shared by `initialize` and `update_config`
+    /// so both enforce identical rules.
+    pub fn validate(&self) -> Result<()> {
+        // Fee configuration
+        let fee_sum = self
+            .platform_fee_bps
+            .checked_add(self.creator_fee_bps)
+            .ok_or(ErrorCode::InvalidFeeConfiguration)?;
+        require!(self.fee_bps == fee_sum, ErrorCode::InvalidFeeConfiguration);
+        require!(self.fee_bps <= 1000, ErrorCode::InvalidFeeConfiguration);
+
+        // Reserve configuration
+        require!(
+            self.initial_virtual_token_reserves > 0,
+            ErrorCode::InvalidReserveConfiguration
+        );
+        require!(
+            self.initial_virtual_sol_reserves > 0,
+            ErrorCode::InvalidReserveConfiguration
+        );
+
+        // Vesting configuration (duration and interval are divisors in claim_vested)
+        require!(
+            self.vesting_cliff_seconds >= 0,
+            ErrorCode::InvalidVestingConfiguration
+        );
+        require!(
+            self.vesting_duration_seconds > 0,
+            ErrorCode::InvalidVestingConfiguration
+        );
+        require!(
+            self.vesting_claim_interval_seconds > 0,
+            ErrorCode::InvalidVestingConfiguration
+        );
+
+        require!(
+            self.launch_cooldown_seconds >= 0,
+            ErrorCode::InvalidCooldownConfiguration
+        );
+        require!(
+            self.max_creator_allocation_bps < 10_000,
+            ErrorCode::InvalidAllocationConfiguration
+        );
+        require!(
+            self.min_vesting_cliff_seconds >= 0 && self.min_vesting_duration_seconds > 0,
+            ErrorCode::InvalidVestingConfiguration
+        );
+        require!(
+            self.fee_split_timelock_seconds >= 0,
+            ErrorCode::InvalidFeeSplitConfiguration
+        );
+
+        // Defaults must themselves be a schedule a launch could choose
+        self.validate_vesting_schedule(
+            self.creator_allocation_bps,
+            self.vesting_cliff_seconds,
+            self.vesting_duration_seconds,
+            self.vesting_claim_interval_seconds,
+        )
+        .map_err(|_| error!(ErrorCode::InvalidVestingConfiguration))?;
+
+        // Burn price bounds (a zero maximum means unbounded)
+        require!(
+            self.max_burn_sol_price == 0 || self.min_burn_sol_price <= self.max_burn_sol_price,
+            ErrorCode::InvalidBurnPriceConfiguration
+        );
+        require!(
+            self.burn_price_update_cooldown_seconds >= 0,
+            ErrorCode::InvalidBurnPriceConfiguration
+        );
+
+        require!(
+            self.unstake_cooldown_seconds >= 0,
+            ErrorCode::InvalidStakingConfiguration
+        );
+        require!(
+            self.max_initial_buy_bps <= 10_000,
+            ErrorCode::InvalidInitialBuyConfiguration
+        );
+        require!(
+            self.launch_window_seconds >= 0,
+            ErrorCode::InvalidLaunchWindowConfiguration
+        );
+        require!(
+            self.referral_fee_bps <= 10_000,
+            ErrorCode::InvalidReferralConfiguration
+        );
+        require!(
+            self.allowed_curve_kinds != 0 && self.allowed_curve_kinds & !CurveKind::ALL_MASK == 0,
+            ErrorCode::InvalidCurveConfiguration
+        );
+        require!(
+            self.min_total_supply > 0 && self.min_total_supply <= self.max_total_supply,
+            ErrorCode::InvalidSupplyConfiguration
+        );
+        require!(
+            self.min_token_decimals <= self.max_token_decimals
+                && self.max_token_decimals <= MAX_TOKEN_DECIMALS,
+            ErrorCode::InvalidSupplyConfiguration
+        );
+
+        Ok(())
+    }
+
+    /// Check a launch's creator allocation and vesting schedule against the
+    /// platform bounds: allocation capped, cliff and duration floored, and the
+    /// claim interval no longer than the duration.
+    pub fn validate_vesting_schedule(
+        &self,
+        creator_allocation_bps: u16,
+        cliff_seconds: i64,
+        duration_seconds: i64,
+        claim_interval_seconds: i64,
+    ) -> Result<()> {
+        require!(
+            creator_allocation_bps <= self.max_creator_allocation_bps,
+            ErrorCode::InvalidVestingSchedule
+        );
+        require!(
+            cliff_seconds >= self.min_vesting_cliff_seconds,
+            ErrorCode::InvalidVestingSchedule
+        );
+        require!(
+            duration_seconds >= self.min_vesting_duration_seconds,
+            ErrorCode::InvalidVestingSchedule
+        );
+        require!(
+            claim_interval_seconds > 0 && claim_interval_seconds <= duration_seconds,
+            ErrorCode::InvalidVestingSchedule
+        );
+        Ok(())
+    }
+
+    /// Check a creator-chosen burn price against the platform bounds.
+    /// A price of 0 disables burns and is always allowed.
+    pub fn validate_burn_sol_price(&self, burn_sol_price: u64) -> Result<()> {
+        if burn_sol_price == 0 {
+            return Ok(());
+        }
+        require!(
+            burn_sol_price >= self.min_burn_sol_price,
+            ErrorCode::BurnPriceOutOfBounds
+        );
+        require!(
+            self.max_burn_sol_price == 0 || burn_sol_price <= self.max_burn_sol_price,
+            ErrorCode::BurnPriceOutOfBounds
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Tokens a wallet has bought on one curve during its launch window, used to
+/// enforce the per-wallet cap.
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchBuys {
+    /// Buyer wallet
+    pub buyer: Pubkey,
+    /// Creator token being bought
+    pub token_mint: Pubkey,
+    /// Cumulative tokens bought inside the launch window
+    pub tokens_bought: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
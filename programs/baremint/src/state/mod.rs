@@ -1,9 +1,25 @@
+pub mod access_pass;
+pub mod access_receipt;
 pub mod bonding_curve;
 pub mod creator_profile;
+pub mod fee_split;
 pub mod global_config;
+pub mod launch_buys;
+pub mod premium_content;
+pub mod referrer;
+pub mod stake_position;
 pub mod vesting;
+pub mod wallet_share;
 
+pub use access_pass::*;
+pub use access_receipt::*;
 pub use bonding_curve::*;
 pub use creator_profile::*;
+pub use fee_split::*;
 pub use global_config::*;
+pub use launch_buys::*;
+pub use premium_content::*;
+pub use referrer::*;
+pub use stake_position::*;
 pub use vesting::*;
+pub use wallet_share::*;
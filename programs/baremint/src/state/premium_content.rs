@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+/// Creator-registered price for a single piece of premium content. When this
+/// account exists, burn_for_access prices the burn from it instead of the
+/// curve-wide `burn_sol_price`.
+#[account]
+#[derive(InitSpace)]
+pub struct PremiumContent {
+    /// Creator token the content is gated by
+    pub token_mint: Pubkey,
+    /// Off-chain content identifier (e.g. hash of the post ID)
+    pub content_id: [u8; 32],
+    /// SOL-denominated burn cost for this content (0 = unlocks disabled)
+    pub burn_sol_price: u64,
+    /// Maximum number of unlocks (0 = unlimited)
+    pub max_unlocks: u32,
+    /// Number of unlocks so far
+    pub unlock_count: u32,
+    /// Unix timestamp after which the content can no longer be unlocked (0 = never)
+    pub expires_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PremiumContent {
+    /// An expiry must be 0 (never) or still in the future, otherwise the
+    /// content could never be unlocked.
+    pub fn validate_expires_at(expires_at: i64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            expires_at == 0 || expires_at > now,
+            ErrorCode::InvalidContentExpiry
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Registered referrer. Referral fees from trades accrue as lamports on this
+/// PDA until claimed.
+#[account]
+#[derive(InitSpace)]
+pub struct Referrer {
+    /// Wallet that registered and can claim
+    pub authority: Pubkey,
+    /// Unclaimed referral fees (tracked in PDA lamports)
+    pub fees_accrued: u64,
+    /// Lifetime referral fees earned (analytics counter)
+    pub total_earned: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
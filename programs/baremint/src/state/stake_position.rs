@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Tokens a holder has locked for a creator. Serves as the on-chain proof of
+/// membership; the tokens themselves sit in the `stake_vault` PDA.
+#[account]
+#[derive(InitSpace)]
+pub struct StakePosition {
+    /// Holder who staked
+    pub owner: Pubkey,
+    /// Creator token being staked
+    pub token_mint: Pubkey,
+    /// Tokens currently locked in the vault
+    pub amount: u64,
+    /// Timestamp of the most recent stake (restarts the lock)
+    pub locked_since: i64,
+    /// Unstake cooldown snapshotted from GlobalConfig at the most recent stake
+    pub unstake_cooldown_seconds: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
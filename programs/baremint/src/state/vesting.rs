@@ -1,5 +1,21 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ErrorCode;
+use crate::math;
+use crate::state::WalletShare;
+
+/// Most wallets a creator can split the vesting allocation between
+pub const MAX_VESTING_BENEFICIARIES: usize = 5;
+
+/// One wallet's share of a vesting allocation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct VestingBeneficiary {
+    /// Wallet that claims this share and its part of the allocation
+    pub share: WalletShare,
+    /// Tokens this wallet has claimed
+    pub claimed_amount: u64,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct VestingAccount {
@@ -9,7 +25,7 @@ pub struct VestingAccount {
     pub token_mint: Pubkey,
     /// Total tokens allocated for vesting
     pub total_allocation: u64,
-    /// Tokens already claimed
+    /// Tokens already claimed across all beneficiaries
     pub claimed_amount: u64,
     /// Timestamp when vesting started
     pub start_timestamp: i64,
@@ -17,4 +33,38 @@ pub struct VestingAccount {
     pub is_revoked: bool,
     /// PDA bump seed
     pub bump: u8,
+    // Fields below were appended after the original layout; see `LEGACY_SPACE`
+    /// Creator allocation chosen at launch, in basis points of total supply
+    pub allocation_bps: u16,
+    /// Seconds after start before anything vests
+    pub cliff_seconds: i64,
+    /// Seconds after the cliff until fully vested
+    pub duration_seconds: i64,
+    /// Vested amounts snap down to multiples of this interval
+    pub claim_interval_seconds: i64,
+    /// Wallets sharing the allocation (just the creator unless split at launch)
+    #[max_len(MAX_VESTING_BENEFICIARIES)]
+    pub beneficiaries: Vec<VestingBeneficiary>,
+}
+
+impl VestingAccount {
+    /// Account size (with discriminator) of the original layout, which ended at
+    /// `bump`. Vesting accounts of this size are grown in place by `migrate_curve`.
+    pub const LEGACY_SPACE: usize = 8 + 32 * 2 + 8 * 3 + 1 + 1;
+
+    /// Portion of `total_vested` owed to the beneficiary at `index`. Shares
+    /// round down, so each one only grows as more vests, and the last
+    /// beneficiary takes the remainder once the whole allocation has vested.
+    pub fn vested_for(&self, index: usize, total_vested: u64) -> Result<u64> {
+        let shares_bps: Vec<u16> = self
+            .beneficiaries
+            .iter()
+            .map(|b| b.share.share_bps)
+            .collect();
+        let parts = math::vested_by_bps(total_vested, self.total_allocation, &shares_bps)?;
+        parts
+            .get(index)
+            .copied()
+            .ok_or(error!(ErrorCode::NotABeneficiary))
+    }
 }
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// One wallet's share of an amount split between several wallets. Used for
+/// vesting beneficiaries and creator fee recipients.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct WalletShare {
+    pub wallet: Pubkey,
+    /// Share in basis points (all shares in a split sum to 10000)
+    pub share_bps: u16,
+}
+
+impl WalletShare {
+    /// A split is valid with 1..=`max_len` unique wallets holding non-zero
+    /// shares that sum to 100%.
+    pub fn is_valid_split(shares: &[WalletShare], max_len: usize) -> bool {
+        if shares.is_empty() || shares.len() > max_len {
+            return false;
+        }
+        let mut total_bps: u32 = 0;
+        for (i, share) in shares.iter().enumerate() {
+            if share.share_bps == 0 || shares[..i].iter().any(|other| other.wallet == share.wallet)
+            {
+                return false;
+            }
+            total_bps += share.share_bps as u32;
+        }
+        total_bps == 10_000
+    }
+}
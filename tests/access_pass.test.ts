@@ -0,0 +1,185 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  createATA,
+  airdropSol,
+  advanceClock,
+  getTokenAccounts,
+  getPassTierPda,
+  getAccessPassPda,
+  SECONDS_PER_DAY,
+  TestContext,
+} from "./setup";
+
+describe("access passes", () => {
+  let ctx: TestContext;
+  let creator: Keypair;
+  let viewer: Keypair;
+  let tokenMint: Keypair;
+  let bondingCurvePda: PublicKey;
+  let viewerAta: PublicKey;
+  let accounts: ReturnType<typeof getTokenAccounts>;
+
+  const DAY_PASS = 0;
+  const DAY_PASS_PRICE = new BN(50_000_000); // 0.05 SOL
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    creator = ctx.context.payer;
+    await initializeGlobalConfig(ctx.program, creator);
+
+    const result = await createToken(ctx.program, ctx.context, creator);
+    tokenMint = result.tokenMint;
+    bondingCurvePda = result.bondingCurvePda;
+    accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+    viewer = Keypair.generate();
+    await airdropSol(ctx.context, viewer.publicKey, 10 * 1_000_000_000);
+    viewerAta = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      viewer.publicKey,
+      viewer
+    );
+    await ctx.program.methods
+      .buy(new BN(2_000_000_000), new BN(0), null)
+      .accounts({
+        buyer: viewer.publicKey,
+        payer: viewer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: viewerAta,
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([viewer])
+      .rpc();
+  });
+
+  function setPassTier(tier: number, price: BN, duration: number, signer = creator) {
+    return ctx.program.methods
+      .setPassTier(tier, price, new BN(duration))
+      .accounts({
+        creator: signer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        passTier: getPassTierPda(ctx.program.programId, tokenMint.publicKey, tier),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  function passPda(): PublicKey {
+    return getAccessPassPda(
+      ctx.program.programId,
+      viewer.publicKey,
+      tokenMint.publicKey
+    );
+  }
+
+  function burnForPass(tier: number) {
+    return ctx.program.methods
+      .burnForPass(tier)
+      .accounts({
+        viewer: viewer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        viewerTokenAccount: viewerAta,
+        passTier: getPassTierPda(ctx.program.programId, tokenMint.publicKey, tier),
+        accessPass: passPda(),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([viewer])
+      .rpc();
+  }
+
+  it("creates a pass expiring after the tier duration", async () => {
+    await setPassTier(DAY_PASS, DAY_PASS_PRICE, SECONDS_PER_DAY);
+    const clock = await ctx.context.banksClient.getClock();
+
+    await burnForPass(DAY_PASS);
+
+    const pass = await ctx.program.account.accessPass.fetch(passPda());
+    expect(pass.expiresAt.toString()).toBe(
+      (clock.unixTimestamp + BigInt(SECONDS_PER_DAY)).toString()
+    );
+    expect(pass.lastTier).toBe(DAY_PASS);
+  });
+
+  it("extends an active pass from its current expiry", async () => {
+    await setPassTier(DAY_PASS, DAY_PASS_PRICE, SECONDS_PER_DAY);
+    await burnForPass(DAY_PASS);
+    const first = await ctx.program.account.accessPass.fetch(passPda());
+
+    await advanceClock(ctx.context, 3600);
+    await burnForPass(DAY_PASS);
+
+    const second = await ctx.program.account.accessPass.fetch(passPda());
+    expect(second.expiresAt.toNumber()).toBe(
+      first.expiresAt.toNumber() + SECONDS_PER_DAY
+    );
+  });
+
+  it("restarts an expired pass from the current time", async () => {
+    await setPassTier(DAY_PASS, DAY_PASS_PRICE, SECONDS_PER_DAY);
+    await burnForPass(DAY_PASS);
+
+    await advanceClock(ctx.context, 2 * SECONDS_PER_DAY);
+    const clock = await ctx.context.banksClient.getClock();
+    await burnForPass(DAY_PASS);
+
+    const pass = await ctx.program.account.accessPass.fetch(passPda());
+    expect(pass.expiresAt.toString()).toBe(
+      (clock.unixTimestamp + BigInt(SECONDS_PER_DAY)).toString()
+    );
+  });
+
+  it("burns tokens and accrues fees like burn_for_access", async () => {
+    await setPassTier(DAY_PASS, DAY_PASS_PRICE, SECONDS_PER_DAY);
+    const curveBefore = await ctx.program.account.bondingCurve.fetch(
+      bondingCurvePda
+    );
+
+    await burnForPass(DAY_PASS);
+
+    const curveAfter = await ctx.program.account.bondingCurve.fetch(
+      bondingCurvePda
+    );
+    expect(
+      BigInt(curveAfter.tokenTotalSupply.toString()) <
+        BigInt(curveBefore.tokenTotalSupply.toString())
+    ).toBe(true);
+    expect(
+      BigInt(curveAfter.creatorFeesAccrued.toString()) >
+        BigInt(curveBefore.creatorFeesAccrued.toString())
+    ).toBe(true);
+  });
+
+  it("rejects a disabled tier", async () => {
+    await setPassTier(DAY_PASS, new BN(0), SECONDS_PER_DAY);
+    await expect(burnForPass(DAY_PASS)).rejects.toThrow(/BurnDisabled/i);
+  });
+
+  it("rejects tier changes by a non-creator", async () => {
+    await expect(
+      setPassTier(DAY_PASS, DAY_PASS_PRICE, SECONDS_PER_DAY, viewer)
+    ).rejects.toThrow();
+  });
+});
@@ -0,0 +1,109 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  airdropSol,
+  TestContext,
+} from "./setup";
+
+describe("authority transfer", () => {
+  let ctx: TestContext;
+  let globalConfigPda: PublicKey;
+  let newAuthority: Keypair;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    globalConfigPda = await initializeGlobalConfig(
+      ctx.program,
+      ctx.context.payer
+    );
+    newAuthority = Keypair.generate();
+    await airdropSol(ctx.context, newAuthority.publicKey, 1_000_000_000);
+  });
+
+  async function propose(target: PublicKey) {
+    const authority = ctx.context.payer;
+    await ctx.program.methods
+      .proposeAuthority(target)
+      .accounts({
+        authority: authority.publicKey,
+        globalConfig: globalConfigPda,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  it("moves authority only after the new key accepts", async () => {
+    await propose(newAuthority.publicKey);
+
+    let config = await ctx.program.account.globalConfig.fetch(globalConfigPda);
+    expect(config.authority.toBase58()).toBe(
+      ctx.context.payer.publicKey.toBase58()
+    );
+    expect(config.pendingAuthority.toBase58()).toBe(
+      newAuthority.publicKey.toBase58()
+    );
+
+    await ctx.program.methods
+      .acceptAuthority()
+      .accounts({
+        newAuthority: newAuthority.publicKey,
+        globalConfig: globalConfigPda,
+      })
+      .signers([newAuthority])
+      .rpc();
+
+    config = await ctx.program.account.globalConfig.fetch(globalConfigPda);
+    expect(config.authority.toBase58()).toBe(newAuthority.publicKey.toBase58());
+    expect(config.pendingAuthority.toBase58()).toBe(
+      PublicKey.default.toBase58()
+    );
+  });
+
+  it("rejects acceptance by a key other than the pending authority", async () => {
+    await propose(newAuthority.publicKey);
+
+    const other = Keypair.generate();
+    await airdropSol(ctx.context, other.publicKey, 1_000_000_000);
+
+    await expect(
+      ctx.program.methods
+        .acceptAuthority()
+        .accounts({
+          newAuthority: other.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([other])
+        .rpc()
+    ).rejects.toThrow();
+  });
+
+  it("rejects acceptance after the proposal is cancelled", async () => {
+    await propose(newAuthority.publicKey);
+    await propose(PublicKey.default);
+
+    await expect(
+      ctx.program.methods
+        .acceptAuthority()
+        .accounts({
+          newAuthority: newAuthority.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([newAuthority])
+        .rpc()
+    ).rejects.toThrow();
+  });
+
+  it("rejects proposals from a non-authority signer", async () => {
+    await expect(
+      ctx.program.methods
+        .proposeAuthority(newAuthority.publicKey)
+        .accounts({
+          authority: newAuthority.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([newAuthority])
+        .rpc()
+    ).rejects.toThrow();
+  });
+});
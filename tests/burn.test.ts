@@ -1,6 +1,9 @@
 import { Program } from "@coral-xyz/anchor";
 import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 import BN from "bn.js";
 import { Baremint } from "../target/types/baremint";
 import {
@@ -11,7 +14,11 @@ import {
   airdropSol,
   getTokenAccounts,
   calculateFee,
+  splitFee,
   calculateTokensForSolValue,
+  contentIdFor,
+  getAccessReceiptPda,
+  getPremiumContentPda,
   DEFAULT_FEE_BPS,
   TOTAL_SUPPLY,
   TestContext,
@@ -27,6 +34,7 @@ describe("burn_for_access", () => {
   let accounts: ReturnType<typeof getTokenAccounts>;
 
   const BURN_SOL_PRICE = new BN(100_000_000); // 0.1 SOL
+  const CONTENT_ID = contentIdFor("post-1");
 
   beforeEach(async () => {
     ctx = await setupTest();
@@ -56,21 +64,58 @@ describe("burn_for_access", () => {
 
     // Viewer buys tokens (2 SOL worth)
     await ctx.program.methods
-      .buy(new BN(2_000_000_000), new BN(0))
+      .buy(new BN(2_000_000_000), new BN(0), null)
       .accounts({
         buyer: viewer.publicKey,
+        payer: viewer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: viewerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([viewer])
       .rpc();
   });
 
+  function receiptPda(
+    viewerKey: PublicKey = viewer.publicKey,
+    mint: PublicKey = tokenMint.publicKey,
+    contentId: number[] = CONTENT_ID
+  ): PublicKey {
+    return getAccessReceiptPda(ctx.program.programId, viewerKey, mint, contentId);
+  }
+
+  function contentPda(
+    mint: PublicKey = tokenMint.publicKey,
+    contentId: number[] = CONTENT_ID
+  ): PublicKey {
+    return getPremiumContentPda(ctx.program.programId, mint, contentId);
+  }
+
+  function burn(contentId: number[]) {
+    return ctx.program.methods
+      .burnForAccess(contentId)
+      .accounts({
+        viewer: viewer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        viewerTokenAccount: viewerAta,
+        premiumContent: contentPda(tokenMint.publicKey, contentId),
+        accessReceipt: receiptPda(viewer.publicKey, tokenMint.publicKey, contentId),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([viewer])
+      .rpc();
+  }
+
   it("burn_for_access destroys correct number of tokens based on SOL price", async () => {
     const curveBefore = await ctx.program.account.bondingCurve.fetch(
       bondingCurvePda
@@ -87,14 +132,17 @@ describe("burn_for_access", () => {
     );
 
     await ctx.program.methods
-      .burnForAccess()
+      .burnForAccess(CONTENT_ID)
       .accounts({
         viewer: viewer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         viewerTokenAccount: viewerAta,
+        premiumContent: contentPda(),
+        accessReceipt: receiptPda(),
         tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .signers([viewer])
       .rpc();
@@ -117,14 +165,17 @@ describe("burn_for_access", () => {
     );
 
     await ctx.program.methods
-      .burnForAccess()
+      .burnForAccess(CONTENT_ID)
       .accounts({
         viewer: viewer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         viewerTokenAccount: viewerAta,
+        premiumContent: contentPda(),
+        accessReceipt: receiptPda(),
         tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .signers([viewer])
       .rpc();
@@ -148,18 +199,21 @@ describe("burn_for_access", () => {
 
     const burnSolPrice = BigInt(BURN_SOL_PRICE.toString());
     const totalFee = calculateFee(burnSolPrice, DEFAULT_FEE_BPS);
-    const expectedPlatformFee = totalFee / BigInt(2);
+    const expectedPlatformFee = splitFee(totalFee).platformFee;
     const expectedCreatorFee = totalFee - expectedPlatformFee;
 
     await ctx.program.methods
-      .burnForAccess()
+      .burnForAccess(CONTENT_ID)
       .accounts({
         viewer: viewer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         viewerTokenAccount: viewerAta,
+        premiumContent: contentPda(),
+        accessReceipt: receiptPda(),
         tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .signers([viewer])
       .rpc();
@@ -202,15 +256,19 @@ describe("burn_for_access", () => {
       viewer
     );
     await ctx.program.methods
-      .buy(new BN(1_000_000_000), new BN(0))
+      .buy(new BN(1_000_000_000), new BN(0), null)
       .accounts({
         buyer: viewer.publicKey,
+        payer: viewer.publicKey,
         globalConfig: accounts2.globalConfigPda,
         bondingCurve: result2.bondingCurvePda,
         tokenMint: result2.tokenMint.publicKey,
         curveTokenAccount: accounts2.curveTokenAccountPda,
         buyerTokenAccount: viewerAta2,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([viewer])
@@ -219,14 +277,20 @@ describe("burn_for_access", () => {
     // Try to burn -- should fail
     await expect(
       ctx.program.methods
-        .burnForAccess()
+        .burnForAccess(CONTENT_ID)
         .accounts({
           viewer: viewer.publicKey,
           globalConfig: accounts2.globalConfigPda,
           bondingCurve: result2.bondingCurvePda,
           tokenMint: result2.tokenMint.publicKey,
           viewerTokenAccount: viewerAta2,
+          premiumContent: contentPda(result2.tokenMint.publicKey),
+          accessReceipt: receiptPda(
+            viewer.publicKey,
+            result2.tokenMint.publicKey
+          ),
           tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
         })
         .signers([viewer])
         .rpc()
@@ -247,15 +311,19 @@ describe("burn_for_access", () => {
 
     // Buy minimal tokens (very small amount)
     await ctx.program.methods
-      .buy(new BN(1000), new BN(0)) // Extremely small buy
+      .buy(new BN(1000), new BN(0), null) // Extremely small buy
       .accounts({
         buyer: viewer2.publicKey,
+        payer: viewer2.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: viewer2Ata,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([viewer2])
@@ -264,14 +332,17 @@ describe("burn_for_access", () => {
     // Try to burn -- should fail (not enough tokens for 0.1 SOL worth)
     await expect(
       ctx.program.methods
-        .burnForAccess()
+        .burnForAccess(CONTENT_ID)
         .accounts({
           viewer: viewer2.publicKey,
           globalConfig: accounts.globalConfigPda,
           bondingCurve: bondingCurvePda,
           tokenMint: tokenMint.publicKey,
           viewerTokenAccount: viewer2Ata,
+          premiumContent: contentPda(),
+          accessReceipt: receiptPda(viewer2.publicKey),
           tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
         })
         .signers([viewer2])
         .rpc()
@@ -285,14 +356,17 @@ describe("burn_for_access", () => {
     const supplyBefore = BigInt(curveBefore.tokenTotalSupply.toString());
 
     await ctx.program.methods
-      .burnForAccess()
+      .burnForAccess(CONTENT_ID)
       .accounts({
         viewer: viewer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         viewerTokenAccount: viewerAta,
+        premiumContent: contentPda(),
+        accessReceipt: receiptPda(),
         tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .signers([viewer])
       .rpc();
@@ -308,7 +382,7 @@ describe("burn_for_access", () => {
   it("fees from burn are correctly split between platform and creator", async () => {
     const burnSolPrice = BigInt(BURN_SOL_PRICE.toString());
     const totalFee = calculateFee(burnSolPrice, DEFAULT_FEE_BPS);
-    const expectedPlatformFee = totalFee / BigInt(2);
+    const expectedPlatformFee = splitFee(totalFee).platformFee;
     const expectedCreatorFee = totalFee - expectedPlatformFee;
 
     // Verify the split is 50/50 of total fee
@@ -322,14 +396,17 @@ describe("burn_for_access", () => {
     );
 
     await ctx.program.methods
-      .burnForAccess()
+      .burnForAccess(CONTENT_ID)
       .accounts({
         viewer: viewer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         viewerTokenAccount: viewerAta,
+        premiumContent: contentPda(),
+        accessReceipt: receiptPda(),
         tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .signers([viewer])
       .rpc();
@@ -348,4 +425,22 @@ describe("burn_for_access", () => {
     expect(platformIncrease).toBe(expectedPlatformFee);
     expect(creatorIncrease).toBe(expectedCreatorFee);
   });
+
+  it("records an AccessReceipt for the unlocked content", async () => {
+    await burn(CONTENT_ID);
+
+    const receipt = await ctx.program.account.accessReceipt.fetch(receiptPda());
+    expect(receipt.viewer.toBase58()).toBe(viewer.publicKey.toBase58());
+    expect(receipt.tokenMint.toBase58()).toBe(tokenMint.publicKey.toBase58());
+    expect(receipt.contentId).toEqual(CONTENT_ID);
+    expect(receipt.solValue.toString()).toBe(BURN_SOL_PRICE.toString());
+    expect(receipt.tokensBurned.toNumber()).toBeGreaterThan(0);
+  });
+
+  it("rejects a second burn for the same content but allows other content", async () => {
+    await burn(CONTENT_ID);
+    await expect(burn(CONTENT_ID)).rejects.toThrow();
+
+    await burn(contentIdFor("post-2"));
+  });
 });
@@ -0,0 +1,104 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  airdropSol,
+  advanceClock,
+  getTokenAccounts,
+  SECONDS_PER_DAY,
+  EMPTY_CONFIG_PARAMS,
+  TestContext,
+} from "./setup";
+
+describe("update_burn_price", () => {
+  let ctx: TestContext;
+  let creator: Keypair;
+  let tokenMint: Keypair;
+  let bondingCurvePda: PublicKey;
+  let accounts: ReturnType<typeof getTokenAccounts>;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    creator = ctx.context.payer;
+    await initializeGlobalConfig(ctx.program, creator);
+
+    const result = await createToken(
+      ctx.program,
+      ctx.context,
+      creator,
+      new BN(100_000_000)
+    );
+    tokenMint = result.tokenMint;
+    bondingCurvePda = result.bondingCurvePda;
+    accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+  });
+
+  function updateBurnPrice(price: BN, signer: Keypair = creator) {
+    return ctx.program.methods
+      .updateBurnPrice(price)
+      .accounts({
+        creator: signer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async function updateConfig(params: Record<string, BN | number>) {
+    await ctx.program.methods
+      .updateConfig({ ...EMPTY_CONFIG_PARAMS, ...params })
+      .accounts({
+        authority: ctx.context.payer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+      })
+      .signers([ctx.context.payer])
+      .rpc();
+  }
+
+  it("creator can change and disable the burn price", async () => {
+    await updateBurnPrice(new BN(250_000_000));
+    let curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.burnSolPrice.toNumber()).toBe(250_000_000);
+
+    await updateBurnPrice(new BN(0));
+    curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.burnSolPrice.toNumber()).toBe(0);
+  });
+
+  it("rejects a signer other than the curve creator", async () => {
+    const other = Keypair.generate();
+    await airdropSol(ctx.context, other.publicKey, 1_000_000_000);
+
+    await expect(
+      updateBurnPrice(new BN(250_000_000), other)
+    ).rejects.toThrow();
+  });
+
+  it("enforces platform min/max bounds but always allows 0", async () => {
+    await updateConfig({
+      minBurnSolPrice: new BN(50_000_000),
+      maxBurnSolPrice: new BN(500_000_000),
+    });
+
+    await expect(updateBurnPrice(new BN(10_000_000))).rejects.toThrow();
+    await expect(updateBurnPrice(new BN(600_000_000))).rejects.toThrow();
+    await updateBurnPrice(new BN(0));
+  });
+
+  it("rate-limits updates by the configured cooldown", async () => {
+    await updateConfig({ burnPriceUpdateCooldownSeconds: new BN(SECONDS_PER_DAY) });
+
+    await updateBurnPrice(new BN(200_000_000));
+    await expect(updateBurnPrice(new BN(300_000_000))).rejects.toThrow();
+
+    await advanceClock(ctx.context, SECONDS_PER_DAY);
+    await updateBurnPrice(new BN(300_000_000));
+
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.burnSolPrice.toNumber()).toBe(300_000_000);
+  });
+});
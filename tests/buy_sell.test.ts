@@ -1,18 +1,30 @@
 import { Program } from "@coral-xyz/anchor";
-import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createTransferInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import BN from "bn.js";
 import { Baremint } from "../target/types/baremint";
 import {
   setupTest,
   initializeGlobalConfig,
   createToken,
+  advanceClock,
   createATA,
   airdropSol,
   getTokenAccounts,
   calculateBuyTokens,
   calculateSellSol,
   calculateFee,
+  splitFee,
   LAMPORTS_PER_SOL,
   DEFAULT_FEE_BPS,
   DEFAULT_PLATFORM_FEE_BPS,
@@ -70,15 +82,19 @@ describe("buy_sell", () => {
     );
 
     await ctx.program.methods
-      .buy(solAmount, new BN(0))
+      .buy(solAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -101,15 +117,19 @@ describe("buy_sell", () => {
     const solAmount = new BN(1_000_000_000);
 
     await ctx.program.methods
-      .buy(solAmount, new BN(0))
+      .buy(solAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -120,8 +140,8 @@ describe("buy_sell", () => {
     );
 
     const totalFee = calculateFee(BigInt(solAmount.toString()), DEFAULT_FEE_BPS);
-    // Program does total_fee / 2 for platform, total_fee - platform for creator
-    const expectedPlatformFee = totalFee / BigInt(2);
+    // Platform share floored by bps, remainder to creator
+    const expectedPlatformFee = splitFee(totalFee).platformFee;
     const expectedCreatorFee = totalFee - expectedPlatformFee;
 
     expect(curveAfter.platformFeesAccrued.toString()).toBe(
@@ -140,15 +160,19 @@ describe("buy_sell", () => {
     )!.lamports;
 
     await ctx.program.methods
-      .buy(solAmount, new BN(0))
+      .buy(solAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -168,15 +192,19 @@ describe("buy_sell", () => {
     // Use a very low min -- should pass
     await expect(
       ctx.program.methods
-        .buy(solAmount, new BN(1))
+        .buy(solAmount, new BN(1), null)
         .accounts({
           buyer: buyer.publicKey,
+          payer: buyer.publicKey,
           globalConfig: accounts.globalConfigPda,
           bondingCurve: bondingCurvePda,
           tokenMint: tokenMint.publicKey,
           curveTokenAccount: accounts.curveTokenAccountPda,
           buyerTokenAccount: buyerAta,
+          launchBuys: null,
+          referrer: null,
           tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([buyer])
@@ -191,15 +219,19 @@ describe("buy_sell", () => {
 
     await expect(
       ctx.program.methods
-        .buy(solAmount, absurdMin)
+        .buy(solAmount, absurdMin, null)
         .accounts({
           buyer: buyer.publicKey,
+          payer: buyer.publicKey,
           globalConfig: accounts.globalConfigPda,
           bondingCurve: bondingCurvePda,
           tokenMint: tokenMint.publicKey,
           curveTokenAccount: accounts.curveTokenAccountPda,
           buyerTokenAccount: buyerAta,
+          launchBuys: null,
+          referrer: null,
           tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([buyer])
@@ -223,15 +255,19 @@ describe("buy_sell", () => {
     );
 
     await ctx.program.methods
-      .buy(solAmount, new BN(0))
+      .buy(solAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -273,15 +309,19 @@ describe("buy_sell", () => {
 
     // First buy
     await ctx.program.methods
-      .buy(solAmount, new BN(0))
+      .buy(solAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -304,15 +344,19 @@ describe("buy_sell", () => {
 
     // Second buy with same SOL
     await ctx.program.methods
-      .buy(solAmount, new BN(0))
+      .buy(solAmount, new BN(0), null)
       .accounts({
         buyer: buyer2.publicKey,
+        payer: buyer2.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyer2Ata,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer2])
@@ -332,15 +376,19 @@ describe("buy_sell", () => {
     const buyAmount = new BN(2_000_000_000); // Buy 2 SOL worth first
 
     await ctx.program.methods
-      .buy(buyAmount, new BN(0))
+      .buy(buyAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -372,14 +420,16 @@ describe("buy_sell", () => {
     const expectedNet = grossSol - totalFee;
 
     await ctx.program.methods
-      .sell(new BN(sellAmount.toString()), new BN(0))
+      .sell(new BN(sellAmount.toString()), new BN(0), null)
       .accounts({
         seller: buyer.publicKey,
+        payer: null,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         sellerTokenAccount: buyerAta,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -404,15 +454,19 @@ describe("buy_sell", () => {
     const buyAmount = new BN(2_000_000_000);
 
     await ctx.program.methods
-      .buy(buyAmount, new BN(0))
+      .buy(buyAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -439,18 +493,20 @@ describe("buy_sell", () => {
       sellAmount
     );
     const totalFee = calculateFee(grossSol, DEFAULT_FEE_BPS);
-    const expectedPlatformFee = totalFee / BigInt(2);
+    const expectedPlatformFee = splitFee(totalFee).platformFee;
     const expectedCreatorFee = totalFee - expectedPlatformFee;
 
     await ctx.program.methods
-      .sell(new BN(sellAmount.toString()), new BN(0))
+      .sell(new BN(sellAmount.toString()), new BN(0), null)
       .accounts({
         seller: buyer.publicKey,
+        payer: null,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         sellerTokenAccount: buyerAta,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -473,15 +529,19 @@ describe("buy_sell", () => {
     const buyAmount = new BN(2_000_000_000);
 
     await ctx.program.methods
-      .buy(buyAmount, new BN(0))
+      .buy(buyAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -497,14 +557,16 @@ describe("buy_sell", () => {
     // Very low min -- should pass
     await expect(
       ctx.program.methods
-        .sell(new BN(sellAmount.toString()), new BN(1))
+        .sell(new BN(sellAmount.toString()), new BN(1), null)
         .accounts({
           seller: buyer.publicKey,
+          payer: null,
           globalConfig: accounts.globalConfigPda,
           bondingCurve: bondingCurvePda,
           tokenMint: tokenMint.publicKey,
           curveTokenAccount: accounts.curveTokenAccountPda,
           sellerTokenAccount: buyerAta,
+          referrer: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -517,15 +579,19 @@ describe("buy_sell", () => {
     const buyAmount = new BN(1_000_000_000);
 
     await ctx.program.methods
-      .buy(buyAmount, new BN(0))
+      .buy(buyAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -539,14 +605,16 @@ describe("buy_sell", () => {
     const sellAmount = tokensHeld / BigInt(2);
     await expect(
       ctx.program.methods
-        .sell(new BN(sellAmount.toString()), new BN("999999999999999"))
+        .sell(new BN(sellAmount.toString()), new BN("999999999999999"), null)
         .accounts({
           seller: buyer.publicKey,
+          payer: null,
           globalConfig: accounts.globalConfigPda,
           bondingCurve: bondingCurvePda,
           tokenMint: tokenMint.publicKey,
           curveTokenAccount: accounts.curveTokenAccountPda,
           sellerTokenAccount: buyerAta,
+          referrer: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -559,15 +627,19 @@ describe("buy_sell", () => {
     const buyAmount = new BN(2_000_000_000);
 
     await ctx.program.methods
-      .buy(buyAmount, new BN(0))
+      .buy(buyAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -590,14 +662,16 @@ describe("buy_sell", () => {
     );
 
     await ctx.program.methods
-      .sell(new BN(sellAmount.toString()), new BN(0))
+      .sell(new BN(sellAmount.toString()), new BN(0), null)
       .accounts({
         seller: buyer.publicKey,
+        payer: null,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         sellerTokenAccount: buyerAta,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -637,15 +711,19 @@ describe("buy_sell", () => {
 
     // First buy (builds reserves buffer)
     await ctx.program.methods
-      .buy(solAmount, new BN(0))
+      .buy(solAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -661,15 +739,19 @@ describe("buy_sell", () => {
 
     // Second buy
     await ctx.program.methods
-      .buy(solAmount, new BN(0))
+      .buy(solAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -682,14 +764,16 @@ describe("buy_sell", () => {
 
     // Sell tokens from second buy
     await ctx.program.methods
-      .sell(new BN(tokensFromSecondBuy.toString()), new BN(0))
+      .sell(new BN(tokensFromSecondBuy.toString()), new BN(0), null)
       .accounts({
         seller: buyer.publicKey,
+        payer: null,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         sellerTokenAccount: buyerAta,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -722,15 +806,19 @@ describe("buy_sell", () => {
 
     // Large buy: 10 SOL
     await ctx.program.methods
-      .buy(new BN(10_000_000_000), new BN(0))
+      .buy(new BN(10_000_000_000), new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -760,15 +848,19 @@ describe("buy_sell", () => {
 
     // First buy
     await ctx.program.methods
-      .buy(buyAmount, new BN(0))
+      .buy(buyAmount, new BN(0), null)
       .accounts({
         buyer: buyer.publicKey,
+        payer: buyer.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -786,14 +878,16 @@ describe("buy_sell", () => {
     const sellAmount = tokensHeld / BigInt(2);
 
     await ctx.program.methods
-      .sell(new BN(sellAmount.toString()), new BN(0))
+      .sell(new BN(sellAmount.toString()), new BN(0), null)
       .accounts({
         seller: buyer.publicKey,
+        payer: null,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         sellerTokenAccount: buyerAta,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -819,4 +913,222 @@ describe("buy_sell", () => {
       BigInt(curveAfter.creatorFeesAccrued.toString()) > BigInt(0)
     ).toBe(true);
   });
+
+  // -------- Deadline Tests --------
+
+  it("first buy creates the buyer's ATA, with rent paid by the sponsor", async () => {
+    const newBuyer = Keypair.generate();
+    await airdropSol(ctx.context, newBuyer.publicKey, 2 * 1_000_000_000);
+    const newBuyerAta = getAssociatedTokenAddressSync(
+      tokenMint.publicKey,
+      newBuyer.publicKey
+    );
+    expect(await ctx.context.banksClient.getAccount(newBuyerAta)).toBeNull();
+
+    const solAmount = new BN(1_000_000_000);
+    await ctx.program.methods
+      .buy(solAmount, new BN(0), null)
+      .accounts({
+        buyer: newBuyer.publicKey,
+        payer: authority.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: newBuyerAta,
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([newBuyer, authority])
+      .rpc();
+
+    const ata = await ctx.context.banksClient.getAccount(newBuyerAta);
+    expect(Buffer.from(ata!.data).readBigUInt64LE(64) > BigInt(0)).toBe(true);
+
+    // Buyer paid exactly the trade amount; the sponsor covered the ATA rent
+    const buyerAccount = await ctx.context.banksClient.getAccount(
+      newBuyer.publicKey
+    );
+    expect(BigInt(buyerAccount!.lamports)).toBe(
+      BigInt(2 * 1_000_000_000) - BigInt(solAmount.toString())
+    );
+  });
+
+  it("sponsor tops up a seller holding no SOL when proceeds are below rent", async () => {
+    // Custodial wallet with tokens but no SOL
+    const custodial = Keypair.generate();
+    const custodialAta = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      custodial.publicKey,
+      authority
+    );
+    await ctx.program.methods
+      .buy(new BN(1_000_000_000), new BN(0), null)
+      .accounts({
+        buyer: buyer.publicKey,
+        payer: buyer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+    const tx = new Transaction().add(
+      createTransferInstruction(
+        buyerAta,
+        custodialAta,
+        buyer.publicKey,
+        1_000_000
+      )
+    );
+    tx.recentBlockhash = ctx.context.lastBlockhash;
+    tx.feePayer = buyer.publicKey;
+    tx.sign(buyer);
+    await ctx.context.banksClient.processTransaction(tx);
+
+    // 0.01 token sells for well under the rent-exempt minimum
+    const sell = (payer: PublicKey | null) =>
+      ctx.program.methods
+        .sell(new BN(10_000), new BN(0), null)
+        .accounts({
+          seller: custodial.publicKey,
+          payer,
+          globalConfig: accounts.globalConfigPda,
+          bondingCurve: bondingCurvePda,
+          tokenMint: tokenMint.publicKey,
+          curveTokenAccount: accounts.curveTokenAccountPda,
+          sellerTokenAccount: custodialAta,
+          referrer: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers(payer ? [custodial, authority] : [custodial])
+        .rpc();
+
+    // Unsponsored, the proceeds would leave the seller funded but below rent
+    await expect(sell(null)).rejects.toThrow();
+
+    await sell(authority.publicKey);
+
+    const rent = await ctx.context.banksClient.getRent();
+    const sellerAccount = await ctx.context.banksClient.getAccount(
+      custodial.publicKey
+    );
+    expect(BigInt(sellerAccount!.lamports)).toBe(rent.minimumBalance(BigInt(0)));
+  });
+
+  it("buy rejects a token account that is not the buyer's ATA", async () => {
+    const otherAta = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      authority.publicKey,
+      authority
+    );
+
+    await expect(
+      ctx.program.methods
+        .buy(new BN(1_000_000_000), new BN(0), null)
+        .accounts({
+          buyer: buyer.publicKey,
+          payer: buyer.publicKey,
+          globalConfig: accounts.globalConfigPda,
+          bondingCurve: bondingCurvePda,
+          tokenMint: tokenMint.publicKey,
+          curveTokenAccount: accounts.curveTokenAccountPda,
+          buyerTokenAccount: otherAta,
+          launchBuys: null,
+          referrer: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc()
+    ).rejects.toThrow();
+  });
+
+  it("buy and sell succeed before the deadline", async () => {
+    const now = Number((await ctx.context.banksClient.getClock()).unixTimestamp);
+    const deadline = new BN(now + 60);
+
+    await ctx.program.methods
+      .buy(new BN(1_000_000_000), new BN(0), deadline)
+      .accounts({
+        buyer: buyer.publicKey,
+        payer: buyer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+
+    const tokensHeld = Buffer.from(
+      (await ctx.context.banksClient.getAccount(buyerAta))!.data
+    ).readBigUInt64LE(64);
+
+    await ctx.program.methods
+      .sell(new BN(tokensHeld.toString()), new BN(0), deadline)
+      .accounts({
+        seller: buyer.publicKey,
+        payer: null,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        sellerTokenAccount: buyerAta,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+  });
+
+  it("buy fails after the deadline has passed", async () => {
+    const now = Number((await ctx.context.banksClient.getClock()).unixTimestamp);
+    const deadline = new BN(now + 60);
+    await advanceClock(ctx.context, 120);
+
+    await expect(
+      ctx.program.methods
+        .buy(new BN(1_000_000_000), new BN(0), deadline)
+        .accounts({
+          buyer: buyer.publicKey,
+          payer: buyer.publicKey,
+          globalConfig: accounts.globalConfigPda,
+          bondingCurve: bondingCurvePda,
+          tokenMint: tokenMint.publicKey,
+          curveTokenAccount: accounts.curveTokenAccountPda,
+          buyerTokenAccount: buyerAta,
+          launchBuys: null,
+          referrer: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc()
+    ).rejects.toThrow();
+  });
 });
@@ -1,6 +1,9 @@
 import { Program } from "@coral-xyz/anchor";
 import { Keypair, PublicKey } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import BN from "bn.js";
 import { Baremint } from "../target/types/baremint";
 import {
@@ -10,7 +13,17 @@ import {
   airdropSol,
   getTokenAccounts,
   advanceClock,
+  getMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  TEST_TOKEN_NAME,
+  TEST_TOKEN_SYMBOL,
+  TEST_TOKEN_URI,
   TOTAL_SUPPLY,
+  DEFAULT_FEE_BPS,
+  DEFAULT_VIRTUAL_SOL_RESERVES,
+  DEFAULT_VIRTUAL_TOKEN_RESERVES,
+  calculateBuyTokens,
+  calculateFee,
   VESTING_AMOUNT,
   CURVE_AMOUNT,
   LAMPORTS_PER_SOL,
@@ -202,4 +215,110 @@ describe("create_token", () => {
     const curve2 = await ctx.program.account.bondingCurve.fetch(pda2);
     expect(curve2.burnSolPrice.toString()).toBe("100000000");
   });
+
+  it("a sponsor can pay rent for a creator holding no SOL", async () => {
+    const creator = Keypair.generate();
+
+    const { bondingCurvePda } = await createToken(
+      ctx.program,
+      ctx.context,
+      creator,
+      new BN(0),
+      true,
+      authority
+    );
+
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.creator.toBase58()).toBe(creator.publicKey.toBase58());
+    expect(await ctx.context.banksClient.getAccount(creator.publicKey)).toBeNull();
+  });
+
+  it("launch without a buy creates no creator token account", async () => {
+    const { tokenMint } = await createToken(ctx.program, ctx.context, authority);
+
+    const creatorAta = getAssociatedTokenAddressSync(
+      tokenMint.publicKey,
+      authority.publicKey
+    );
+    expect(await ctx.context.banksClient.getAccount(creatorAta)).toBeNull();
+  });
+
+  it("launch buy delivers tokens to the creator at the pristine curve price", async () => {
+    const initialBuySol = BigInt(1_000_000_000); // 1 SOL
+    const fee = calculateFee(initialBuySol, DEFAULT_FEE_BPS);
+    const expectedTokens = calculateBuyTokens(
+      BigInt(DEFAULT_VIRTUAL_SOL_RESERVES.toString()),
+      BigInt(DEFAULT_VIRTUAL_TOKEN_RESERVES.toString()),
+      initialBuySol - fee
+    );
+
+    const { tokenMint, bondingCurvePda } = await createToken(
+      ctx.program,
+      ctx.context,
+      authority,
+      new BN(0),
+      true,
+      authority,
+      new BN(initialBuySol.toString()),
+      new BN(expectedTokens.toString())
+    );
+
+    const creatorAta = getAssociatedTokenAddressSync(
+      tokenMint.publicKey,
+      authority.publicKey
+    );
+    const acct = await ctx.context.banksClient.getAccount(creatorAta);
+    expect(Buffer.from(acct!.data).readBigUInt64LE(64)).toBe(expectedTokens);
+
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.realSolReserves.toString()).toBe(
+      (initialBuySol - fee).toString()
+    );
+    expect(curve.realTokenReserves.toString()).toBe(
+      (CURVE_AMOUNT - expectedTokens).toString()
+    );
+  });
+
+  it("launch buy above the platform cap fails", async () => {
+    // 2 SOL buys ~6.4% of supply, above the default 5% cap
+    await expect(
+      createToken(
+        ctx.program,
+        ctx.context,
+        authority,
+        new BN(0),
+        true,
+        authority,
+        new BN(2_000_000_000)
+      )
+    ).rejects.toThrow(/InitialBuyExceedsCap/i);
+  });
+
+  it("creates Metaplex metadata with the platform as update authority", async () => {
+    const { tokenMint } = await createToken(
+      ctx.program,
+      ctx.context,
+      authority
+    );
+
+    const metadata = await ctx.context.banksClient.getAccount(
+      getMetadataPda(tokenMint.publicKey)
+    );
+    expect(metadata).not.toBeNull();
+    expect(metadata!.owner.toBase58()).toBe(
+      TOKEN_METADATA_PROGRAM_ID.toBase58()
+    );
+
+    // Metadata layout: key (1) | update_authority (32) | mint (32) | name ...
+    const data = Buffer.from(metadata!.data);
+    expect(new PublicKey(data.subarray(1, 33)).toBase58()).toBe(
+      authority.publicKey.toBase58()
+    );
+    expect(new PublicKey(data.subarray(33, 65)).toBase58()).toBe(
+      tokenMint.publicKey.toBase58()
+    );
+    expect(data.toString("utf8")).toContain(TEST_TOKEN_NAME);
+    expect(data.toString("utf8")).toContain(TEST_TOKEN_SYMBOL);
+    expect(data.toString("utf8")).toContain(TEST_TOKEN_URI);
+  });
 });
@@ -0,0 +1,173 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  airdropSol,
+  getTokenAccounts,
+  EMPTY_CONFIG_PARAMS,
+  TestContext,
+} from "./setup";
+
+// Bits of GlobalConfig.allowed_curve_kinds, in CurveKind declaration order
+const CONSTANT_PRODUCT = 1 << 0;
+const LINEAR = 1 << 1;
+const EXPONENTIAL = 1 << 2;
+const STEPPED = 1 << 3;
+
+describe("curve kinds", () => {
+  let ctx: TestContext;
+  let authority: Keypair;
+  let globalConfigPda: PublicKey;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    authority = ctx.context.payer;
+    globalConfigPda = await initializeGlobalConfig(ctx.program, authority);
+  });
+
+  function allowCurveKinds(mask: number) {
+    return ctx.program.methods
+      .updateConfig({ ...EMPTY_CONFIG_PARAMS, allowedCurveKinds: mask })
+      .accounts({
+        authority: authority.publicKey,
+        globalConfig: globalConfigPda,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  function launch(curveKind: object) {
+    return createToken(
+      ctx.program,
+      ctx.context,
+      authority,
+      new BN(0),
+      true,
+      authority,
+      new BN(0),
+      new BN(0),
+      curveKind
+    );
+  }
+
+  async function buyAndSell(
+    tokenMint: Keypair,
+    bondingCurvePda: PublicKey
+  ): Promise<{ tokens: bigint; solBack: bigint }> {
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+    const trader = Keypair.generate();
+    await airdropSol(ctx.context, trader.publicKey, 10 * 1_000_000_000);
+    const traderAta = getAssociatedTokenAddressSync(
+      tokenMint.publicKey,
+      trader.publicKey
+    );
+
+    await ctx.program.methods
+      .buy(new BN(1_000_000_000), new BN(0), null)
+      .accounts({
+        buyer: trader.publicKey,
+        payer: trader.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: traderAta,
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([trader])
+      .rpc();
+
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    const tokens = BigInt(curve.tokensSold.toString());
+
+    const balanceBefore = (await ctx.context.banksClient.getAccount(
+      trader.publicKey
+    ))!.lamports;
+    await ctx.program.methods
+      .sell(new BN(tokens.toString()), new BN(0), null)
+      .accounts({
+        seller: trader.publicKey,
+        payer: null,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        sellerTokenAccount: traderAta,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([trader])
+      .rpc();
+    const balanceAfter = (await ctx.context.banksClient.getAccount(
+      trader.publicKey
+    ))!.lamports;
+
+    return { tokens, solBack: BigInt(balanceAfter - balanceBefore) };
+  }
+
+  it("defaults to constant product only", async () => {
+    const config = await ctx.program.account.globalConfig.fetch(globalConfigPda);
+    expect(config.allowedCurveKinds).toBe(CONSTANT_PRODUCT);
+
+    const { bondingCurvePda } = await launch({ constantProduct: {} });
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.curveKind).toEqual({ constantProduct: {} });
+    expect(curve.curveStartPrice.toNumber()).toBe(0);
+    expect(curve.curveEndPrice.toNumber()).toBe(0);
+  });
+
+  it("rejects a curve kind the platform has not allowed", async () => {
+    await expect(launch({ linear: {} })).rejects.toThrow(/CurveKindNotAllowed/);
+  });
+
+  it("rejects an empty or unknown allowed set", async () => {
+    await expect(allowCurveKinds(0)).rejects.toThrow(
+      /InvalidCurveConfiguration/
+    );
+    await expect(allowCurveKinds(1 << 4)).rejects.toThrow(
+      /InvalidCurveConfiguration/
+    );
+  });
+
+  for (const [name, kind] of [
+    ["linear", { linear: {} }],
+    ["exponential", { exponential: {} }],
+    ["stepped", { stepped: {} }],
+  ] as const) {
+    it(`launches and trades a ${name} curve`, async () => {
+      await allowCurveKinds(CONSTANT_PRODUCT | LINEAR | EXPONENTIAL | STEPPED);
+      const { tokenMint, bondingCurvePda } = await launch(kind);
+
+      const curve = await ctx.program.account.bondingCurve.fetch(
+        bondingCurvePda
+      );
+      expect(curve.curveKind).toEqual(kind);
+      expect(curve.curveEndPrice.gt(curve.curveStartPrice)).toBe(true);
+      expect(curve.curveSupply.toString()).toBe(
+        curve.realTokenReserves.toString()
+      );
+
+      const { tokens, solBack } = await buyAndSell(tokenMint, bondingCurvePda);
+      expect(tokens > BigInt(0)).toBe(true);
+      // Fees on both legs: a round trip always returns less than was paid
+      expect(solBack < BigInt(1_000_000_000)).toBe(true);
+
+      const after = await ctx.program.account.bondingCurve.fetch(
+        bondingCurvePda
+      );
+      expect(after.tokensSold.toNumber()).toBe(0);
+    });
+  }
+});
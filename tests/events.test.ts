@@ -0,0 +1,319 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  airdropSol,
+  getTokenAccounts,
+  getMetadataPda,
+  processAndParseEvents,
+  calculateBuyTokens,
+  calculateFee,
+  splitFee,
+  DEFAULT_FEE_BPS,
+  DEFAULT_VIRTUAL_SOL_RESERVES,
+  DEFAULT_VIRTUAL_TOKEN_RESERVES,
+  EMPTY_CONFIG_PARAMS,
+  DEFAULT_VESTING_SCHEDULE,
+  TOKEN_METADATA_PROGRAM_ID,
+  TEST_TOKEN_NAME,
+  TEST_TOKEN_SYMBOL,
+  TEST_TOKEN_URI,
+  TOTAL_SUPPLY,
+  CURVE_AMOUNT,
+  VESTING_AMOUNT,
+  TestContext,
+} from "./setup";
+
+describe("events", () => {
+  let ctx: TestContext;
+  let authority: Keypair;
+  let globalConfigPda: PublicKey;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    authority = ctx.context.payer;
+    globalConfigPda = await initializeGlobalConfig(ctx.program, authority);
+  });
+
+  function findEvent(events: { name: string; data: any }[], name: string) {
+    const event = events.find((e) => e.name === name);
+    expect(event).toBeDefined();
+    return event!.data;
+  }
+
+  function buyTransaction(
+    tokenMint: Keypair,
+    bondingCurvePda: PublicKey,
+    trader: Keypair,
+    solAmount: bigint
+  ) {
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+    return ctx.program.methods
+      .buy(new BN(solAmount.toString()), new BN(0), null)
+      .accounts({
+        buyer: trader.publicKey,
+        payer: trader.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: getAssociatedTokenAddressSync(
+          tokenMint.publicKey,
+          trader.publicKey
+        ),
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .transaction();
+  }
+
+  it("emits TokenCreated with the launch parameters", async () => {
+    const tokenMint = Keypair.generate();
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+    const [creatorProfilePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("creator_profile"), authority.publicKey.toBuffer()],
+      ctx.program.programId
+    );
+
+    const tx = await ctx.program.methods
+      .createToken({
+        burnSolPrice: new BN(0),
+        name: TEST_TOKEN_NAME,
+        symbol: TEST_TOKEN_SYMBOL,
+        uri: TEST_TOKEN_URI,
+        isMutable: true,
+        initialBuySol: new BN(0),
+        minTokensOut: new BN(0),
+        curveKind: { constantProduct: {} },
+        totalSupply: new BN(TOTAL_SUPPLY.toString()),
+        decimals: 6,
+        ...DEFAULT_VESTING_SCHEDULE,
+      })
+      .accounts({
+        creator: authority.publicKey,
+        payer: authority.publicKey,
+        globalConfig: globalConfigPda,
+        creatorProfile: creatorProfilePda,
+        tokenMint: tokenMint.publicKey,
+        bondingCurve: accounts.bondingCurvePda,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        vestingAccount: accounts.vestingAccountPda,
+        vestingTokenAccount: accounts.vestingTokenAccountPda,
+        creatorTokenAccount: null,
+        metadata: getMetadataPda(tokenMint.publicKey),
+        metadataUpdateAuthority: authority.publicKey,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: new PublicKey("SysvarRent111111111111111111111111111111111"),
+      })
+      .transaction();
+
+    const events = await processAndParseEvents(ctx.context, ctx.program, tx, [
+      authority,
+      tokenMint,
+    ]);
+    const created = findEvent(events, "tokenCreated");
+
+    expect(created.tokenMint.toBase58()).toBe(tokenMint.publicKey.toBase58());
+    expect(created.creator.toBase58()).toBe(authority.publicKey.toBase58());
+    expect(created.bondingCurve.toBase58()).toBe(
+      accounts.bondingCurvePda.toBase58()
+    );
+    expect(created.tokenTotalSupply.toString()).toBe(TOTAL_SUPPLY.toString());
+    expect(created.realTokenReserves.toString()).toBe(CURVE_AMOUNT.toString());
+    expect(created.vestingAmount.toString()).toBe(VESTING_AMOUNT.toString());
+    expect(created.virtualTokenReserves.toString()).toBe(
+      DEFAULT_VIRTUAL_TOKEN_RESERVES.toString()
+    );
+    expect(created.virtualSolReserves.toString()).toBe(
+      DEFAULT_VIRTUAL_SOL_RESERVES.toString()
+    );
+    expect(created.decimals).toBe(6);
+    expect(created.curveKind).toEqual({ constantProduct: {} });
+    expect(created.curveSupply.toString()).toBe(CURVE_AMOUNT.toString());
+    expect(created.curveStartPrice.toNumber()).toBe(0);
+    expect(created.curveEndPrice.toNumber()).toBe(0);
+    expect(created.name).toBe(TEST_TOKEN_NAME);
+    expect(created.symbol).toBe(TEST_TOKEN_SYMBOL);
+  });
+
+  it("emits Trade with fees and post-trade reserves for buys and sells", async () => {
+    const { tokenMint, bondingCurvePda } = await createToken(
+      ctx.program,
+      ctx.context,
+      authority
+    );
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+    const trader = Keypair.generate();
+    await airdropSol(ctx.context, trader.publicKey, 10 * 1_000_000_000);
+    const traderAta = getAssociatedTokenAddressSync(
+      tokenMint.publicKey,
+      trader.publicKey
+    );
+
+    const solAmount = BigInt(2_000_000_000);
+    const buyTx = await buyTransaction(
+      tokenMint,
+      bondingCurvePda,
+      trader,
+      solAmount
+    );
+    const buy = findEvent(
+      await processAndParseEvents(ctx.context, ctx.program, buyTx, [trader]),
+      "trade"
+    );
+
+    const totalFee = calculateFee(solAmount, DEFAULT_FEE_BPS);
+    const { platformFee, creatorFee } = splitFee(totalFee);
+    const expectedTokens = calculateBuyTokens(
+      BigInt(DEFAULT_VIRTUAL_SOL_RESERVES.toString()),
+      BigInt(DEFAULT_VIRTUAL_TOKEN_RESERVES.toString()),
+      solAmount - totalFee
+    );
+
+    expect(buy.side).toEqual({ buy: {} });
+    expect(buy.trader.toBase58()).toBe(trader.publicKey.toBase58());
+    expect(buy.solAmount.toString()).toBe(solAmount.toString());
+    expect(buy.tokenAmount.toString()).toBe(expectedTokens.toString());
+    expect(buy.platformFee.toString()).toBe(platformFee.toString());
+    expect(buy.creatorFee.toString()).toBe(creatorFee.toString());
+    expect(buy.referrer.toBase58()).toBe(PublicKey.default.toBase58());
+    expect(buy.referralFee.toNumber()).toBe(0);
+
+    let curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(buy.virtualSolReserves.toString()).toBe(
+      curve.virtualSolReserves.toString()
+    );
+    expect(buy.virtualTokenReserves.toString()).toBe(
+      curve.virtualTokenReserves.toString()
+    );
+    expect(buy.realSolReserves.toString()).toBe(
+      (solAmount - totalFee).toString()
+    );
+    expect(buy.realTokenReserves.toString()).toBe(
+      (CURVE_AMOUNT - expectedTokens).toString()
+    );
+    expect(buy.tokensSold.toString()).toBe(expectedTokens.toString());
+
+    const sellTx = await ctx.program.methods
+      .sell(new BN(expectedTokens.toString()), new BN(0), null)
+      .accounts({
+        seller: trader.publicKey,
+        payer: null,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        sellerTokenAccount: traderAta,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .transaction();
+    const sell = findEvent(
+      await processAndParseEvents(ctx.context, ctx.program, sellTx, [trader]),
+      "trade"
+    );
+
+    curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(sell.side).toEqual({ sell: {} });
+    expect(sell.tokenAmount.toString()).toBe(expectedTokens.toString());
+    expect(sell.realTokenReserves.toString()).toBe(CURVE_AMOUNT.toString());
+    expect(sell.tokensSold.toNumber()).toBe(0);
+    expect(sell.realSolReserves.toString()).toBe(
+      curve.realSolReserves.toString()
+    );
+    expect(sell.virtualSolReserves.toString()).toBe(
+      curve.virtualSolReserves.toString()
+    );
+    expect(
+      BigInt(curve.creatorFeesAccrued.toString()) - creatorFee
+    ).toBe(BigInt(sell.creatorFee.toString()));
+  });
+
+  it("emits tokens_sold so shaped curves can be priced from events", async () => {
+    await ctx.program.methods
+      .updateConfig({ ...EMPTY_CONFIG_PARAMS, allowedCurveKinds: 1 << 1 })
+      .accounts({
+        authority: authority.publicKey,
+        globalConfig: globalConfigPda,
+      })
+      .signers([authority])
+      .rpc();
+    const { tokenMint, bondingCurvePda } = await createToken(
+      ctx.program,
+      ctx.context,
+      authority,
+      new BN(0),
+      true,
+      authority,
+      new BN(0),
+      new BN(0),
+      { linear: {} }
+    );
+    const trader = Keypair.generate();
+    await airdropSol(ctx.context, trader.publicKey, 10 * 1_000_000_000);
+
+    const buy = findEvent(
+      await processAndParseEvents(
+        ctx.context,
+        ctx.program,
+        await buyTransaction(
+          tokenMint,
+          bondingCurvePda,
+          trader,
+          BigInt(1_000_000_000)
+        ),
+        [trader]
+      ),
+      "trade"
+    );
+
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.curveStartPrice.gt(new BN(0))).toBe(true);
+    expect(buy.tokensSold.toString()).toBe(curve.tokensSold.toString());
+    expect(buy.tokensSold.toString()).toBe(buy.tokenAmount.toString());
+  });
+
+  it("emits ConfigUpdated with the resulting config", async () => {
+    const tx = await ctx.program.methods
+      .updateConfig({
+        ...EMPTY_CONFIG_PARAMS,
+        feeBps: 400,
+        platformFeeBps: 300,
+        creatorFeeBps: 100,
+        referralFeeBps: 1_000,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        globalConfig: globalConfigPda,
+      })
+      .transaction();
+    const updated = findEvent(
+      await processAndParseEvents(ctx.context, ctx.program, tx, [authority]),
+      "configUpdated"
+    );
+
+    expect(updated.authority.toBase58()).toBe(authority.publicKey.toBase58());
+    expect(updated.feeBps).toBe(400);
+    expect(updated.platformFeeBps).toBe(300);
+    expect(updated.creatorFeeBps).toBe(100);
+    expect(updated.referralFeeBps).toBe(1_000);
+    // Fields left unchanged are reported at their current values
+    expect(updated.initialVirtualSolReserves.toString()).toBe(
+      DEFAULT_VIRTUAL_SOL_RESERVES.toString()
+    );
+  });
+});
@@ -0,0 +1,222 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  createATA,
+  airdropSol,
+  getTokenAccounts,
+  calculateBuySolForTokens,
+  calculateSellTokensForSol,
+  calculateSellSol,
+  calculateFee,
+  calculateGrossForNet,
+  DEFAULT_FEE_BPS,
+  TestContext,
+} from "./setup";
+
+describe("exact-output trades", () => {
+  let ctx: TestContext;
+  let buyer: Keypair;
+  let tokenMint: Keypair;
+  let bondingCurvePda: PublicKey;
+  let buyerAta: PublicKey;
+  let accounts: ReturnType<typeof getTokenAccounts>;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    const authority = ctx.context.payer;
+    await initializeGlobalConfig(ctx.program, authority);
+
+    const result = await createToken(ctx.program, ctx.context, authority);
+    tokenMint = result.tokenMint;
+    bondingCurvePda = result.bondingCurvePda;
+    accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+    buyer = Keypair.generate();
+    await airdropSol(ctx.context, buyer.publicKey, 100 * 1_000_000_000);
+    buyerAta = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      buyer.publicKey,
+      buyer
+    );
+  });
+
+  function buyAccounts() {
+    return {
+      buyer: buyer.publicKey,
+      payer: buyer.publicKey,
+      globalConfig: accounts.globalConfigPda,
+      bondingCurve: bondingCurvePda,
+      tokenMint: tokenMint.publicKey,
+      curveTokenAccount: accounts.curveTokenAccountPda,
+      buyerTokenAccount: buyerAta,
+      launchBuys: null,
+      referrer: null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  function sellAccounts() {
+    return {
+      seller: buyer.publicKey,
+      payer: null,
+      globalConfig: accounts.globalConfigPda,
+      bondingCurve: bondingCurvePda,
+      tokenMint: tokenMint.publicKey,
+      curveTokenAccount: accounts.curveTokenAccountPda,
+      sellerTokenAccount: buyerAta,
+      referrer: null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  async function tokenBalance(ata: PublicKey): Promise<bigint> {
+    const acct = await ctx.context.banksClient.getAccount(ata);
+    return Buffer.from(acct!.data).readBigUInt64LE(64);
+  }
+
+  it("buy_exact_tokens delivers exactly the requested amount", async () => {
+    const tokenAmount = BigInt("10000000000000"); // 10M tokens
+    const curveBefore = await ctx.program.account.bondingCurve.fetch(
+      bondingCurvePda
+    );
+    const expectedSol = calculateGrossForNet(
+      calculateBuySolForTokens(
+        BigInt(curveBefore.virtualSolReserves.toString()),
+        BigInt(curveBefore.virtualTokenReserves.toString()),
+        tokenAmount
+      ),
+      DEFAULT_FEE_BPS
+    );
+
+    const lamportsBefore = (await ctx.context.banksClient.getAccount(
+      buyer.publicKey
+    ))!.lamports;
+
+    await ctx.program.methods
+      .buyExactTokens(
+        new BN(tokenAmount.toString()),
+        new BN(expectedSol.toString()),
+        null
+      )
+      .accounts(buyAccounts())
+      .signers([buyer])
+      .rpc();
+
+    expect(await tokenBalance(buyerAta)).toBe(tokenAmount);
+
+    const lamportsAfter = (await ctx.context.banksClient.getAccount(
+      buyer.publicKey
+    ))!.lamports;
+    // Buyer paid the quoted SOL (plus the transaction fee)
+    expect(lamportsBefore - lamportsAfter >= expectedSol).toBe(true);
+
+    const curveAfter = await ctx.program.account.bondingCurve.fetch(
+      bondingCurvePda
+    );
+    expect(
+      BigInt(curveBefore.realTokenReserves.toString()) -
+        BigInt(curveAfter.realTokenReserves.toString())
+    ).toBe(tokenAmount);
+  });
+
+  it("buy_exact_tokens fails when the cost exceeds max_sol_in", async () => {
+    const tokenAmount = BigInt("10000000000000");
+    const curve = await ctx.program.account.bondingCurve.fetch(
+      bondingCurvePda
+    );
+    const expectedSol = calculateGrossForNet(
+      calculateBuySolForTokens(
+        BigInt(curve.virtualSolReserves.toString()),
+        BigInt(curve.virtualTokenReserves.toString()),
+        tokenAmount
+      ),
+      DEFAULT_FEE_BPS
+    );
+
+    await expect(
+      ctx.program.methods
+        .buyExactTokens(
+          new BN(tokenAmount.toString()),
+          new BN((expectedSol - BigInt(1)).toString()),
+          null
+        )
+        .accounts(buyAccounts())
+        .signers([buyer])
+        .rpc()
+    ).rejects.toThrow();
+  });
+
+  it("sell_for_exact_sol nets at least the requested SOL with minimal tokens", async () => {
+    await ctx.program.methods
+      .buy(new BN(5_000_000_000), new BN(0), null)
+      .accounts(buyAccounts())
+      .signers([buyer])
+      .rpc();
+
+    const solOut = BigInt(1_000_000_000); // 1 SOL net
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    const virtualSol = BigInt(curve.virtualSolReserves.toString());
+    const virtualToken = BigInt(curve.virtualTokenReserves.toString());
+    const expectedTokens = calculateSellTokensForSol(
+      virtualSol,
+      virtualToken,
+      calculateGrossForNet(solOut, DEFAULT_FEE_BPS)
+    );
+    const grossSol = calculateSellSol(virtualSol, virtualToken, expectedTokens);
+    const expectedNet = grossSol - calculateFee(grossSol, DEFAULT_FEE_BPS);
+    expect(expectedNet >= solOut).toBe(true);
+
+    const tokensBefore = await tokenBalance(buyerAta);
+    await ctx.program.methods
+      .sellForExactSol(
+        new BN(solOut.toString()),
+        new BN(expectedTokens.toString()),
+        null
+      )
+      .accounts(sellAccounts())
+      .signers([buyer])
+      .rpc();
+
+    expect(tokensBefore - (await tokenBalance(buyerAta))).toBe(expectedTokens);
+  });
+
+  it("sell_for_exact_sol fails when more than max_tokens_in is needed", async () => {
+    await ctx.program.methods
+      .buy(new BN(5_000_000_000), new BN(0), null)
+      .accounts(buyAccounts())
+      .signers([buyer])
+      .rpc();
+
+    const solOut = BigInt(1_000_000_000);
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    const expectedTokens = calculateSellTokensForSol(
+      BigInt(curve.virtualSolReserves.toString()),
+      BigInt(curve.virtualTokenReserves.toString()),
+      calculateGrossForNet(solOut, DEFAULT_FEE_BPS)
+    );
+
+    await expect(
+      ctx.program.methods
+        .sellForExactSol(
+          new BN(solOut.toString()),
+          new BN((expectedTokens - BigInt(1)).toString()),
+          null
+        )
+        .accounts(sellAccounts())
+        .signers([buyer])
+        .rpc()
+    ).rejects.toThrow();
+  });
+});
@@ -0,0 +1,265 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  airdropSol,
+  advanceClock,
+  getTokenAccounts,
+  SECONDS_PER_DAY,
+  TestContext,
+} from "./setup";
+
+describe("creator fee splits", () => {
+  let ctx: TestContext;
+  let creator: Keypair;
+  let editor: Keypair;
+  let cohost: Keypair;
+  let tokenMint: Keypair;
+  let bondingCurvePda: PublicKey;
+  let feeSplitPda: PublicKey;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    creator = ctx.context.payer;
+    await initializeGlobalConfig(ctx.program, creator);
+
+    const result = await createToken(ctx.program, ctx.context, creator);
+    tokenMint = result.tokenMint;
+    bondingCurvePda = result.bondingCurvePda;
+    [feeSplitPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_split"), tokenMint.publicKey.toBuffer()],
+      ctx.program.programId
+    );
+
+    editor = Keypair.generate();
+    cohost = Keypair.generate();
+    await airdropSol(ctx.context, editor.publicKey, 1_000_000_000);
+    await airdropSol(ctx.context, cohost.publicKey, 1_000_000_000);
+  });
+
+  function setFeeSplit(
+    recipients: { wallet: PublicKey; shareBps: number }[],
+    signer: Keypair = creator
+  ) {
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+    return ctx.program.methods
+      .setFeeSplit(recipients)
+      .accounts({
+        creator: signer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        feeSplit: feeSplitPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  function distribute(wallets: PublicKey[]) {
+    return ctx.program.methods
+      .distributeCreatorFees()
+      .accounts({
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        feeSplit: feeSplitPda,
+      })
+      .remainingAccounts(
+        wallets.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+      )
+      .rpc();
+  }
+
+  async function trade() {
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+    const trader = Keypair.generate();
+    await airdropSol(ctx.context, trader.publicKey, 10 * 1_000_000_000);
+    await ctx.program.methods
+      .buy(new BN(3_000_000_000), new BN(0), null)
+      .accounts({
+        buyer: trader.publicKey,
+        payer: trader.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: getAssociatedTokenAddressSync(
+          tokenMint.publicKey,
+          trader.publicKey
+        ),
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([trader])
+      .rpc();
+  }
+
+  async function lamports(wallet: PublicKey): Promise<bigint> {
+    return (await ctx.context.banksClient.getAccount(wallet))!.lamports;
+  }
+
+  it("distributes accrued creator fees pro rata with the remainder to the last", async () => {
+    await setFeeSplit([
+      { wallet: creator.publicKey, shareBps: 5_000 },
+      { wallet: editor.publicKey, shareBps: 3_333 },
+      { wallet: cohost.publicKey, shareBps: 1_667 },
+    ]);
+    await trade();
+
+    const curveBefore = await ctx.program.account.bondingCurve.fetch(
+      bondingCurvePda
+    );
+    const accrued = BigInt(curveBefore.creatorFeesAccrued.toString());
+    expect(accrued > BigInt(0)).toBe(true);
+
+    const editorBefore = await lamports(editor.publicKey);
+    const cohostBefore = await lamports(cohost.publicKey);
+
+    // Permissionless: no signer beyond the transaction fee payer
+    await distribute([creator.publicKey, editor.publicKey, cohost.publicKey]);
+
+    const editorShare = (accrued * BigInt(3_333)) / BigInt(10_000);
+    const creatorShare = accrued / BigInt(2);
+    expect((await lamports(editor.publicKey)) - editorBefore).toBe(editorShare);
+    expect((await lamports(cohost.publicKey)) - cohostBefore).toBe(
+      accrued - creatorShare - editorShare
+    );
+
+    const curveAfter = await ctx.program.account.bondingCurve.fetch(
+      bondingCurvePda
+    );
+    expect(curveAfter.creatorFeesAccrued.toNumber()).toBe(0);
+  });
+
+  it("carries a payout that would leave its recipient below rent exemption", async () => {
+    // Never funded, so a tiny share cannot create the account
+    const unfunded = Keypair.generate();
+    await setFeeSplit([
+      { wallet: creator.publicKey, shareBps: 9_990 },
+      { wallet: unfunded.publicKey, shareBps: 10 },
+    ]);
+    await trade();
+
+    const accrued = BigInt(
+      (
+        await ctx.program.account.bondingCurve.fetch(bondingCurvePda)
+      ).creatorFeesAccrued.toString()
+    );
+    const carried = accrued - (accrued * BigInt(9_990)) / BigInt(10_000);
+
+    await distribute([creator.publicKey, unfunded.publicKey]);
+
+    expect(
+      await ctx.context.banksClient.getAccount(unfunded.publicKey)
+    ).toBeNull();
+    let split = await ctx.program.account.feeSplit.fetch(feeSplitPda);
+    expect(split.carriedFees[0].toNumber()).toBe(0);
+    expect(split.carriedFees[1].toString()).toBe(carried.toString());
+    let curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.creatorFeesAccrued.toString()).toBe(carried.toString());
+
+    // Once funded, the carried payout goes out on the next distribution
+    await airdropSol(ctx.context, unfunded.publicKey, 1_000_000_000);
+    await distribute([creator.publicKey, unfunded.publicKey]);
+
+    expect(await lamports(unfunded.publicKey)).toBe(
+      BigInt(1_000_000_000) + carried
+    );
+    split = await ctx.program.account.feeSplit.fetch(feeSplitPda);
+    expect(split.carriedFees[1].toNumber()).toBe(0);
+    curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.creatorFeesAccrued.toNumber()).toBe(0);
+  });
+
+  it("blocks direct creator withdrawal once a split exists", async () => {
+    await setFeeSplit([{ wallet: editor.publicKey, shareBps: 10_000 }]);
+    await trade();
+
+    await expect(
+      ctx.program.methods
+        .withdrawCreatorFees()
+        .accounts({
+          creator: creator.publicKey,
+          bondingCurve: bondingCurvePda,
+          tokenMint: tokenMint.publicKey,
+          feeSplit: feeSplitPda,
+        })
+        .signers([creator])
+        .rpc()
+    ).rejects.toThrow(/FeeSplitActive/);
+  });
+
+  it("rejects recipient accounts that do not match the split", async () => {
+    await setFeeSplit([
+      { wallet: creator.publicKey, shareBps: 5_000 },
+      { wallet: editor.publicKey, shareBps: 5_000 },
+    ]);
+    await trade();
+
+    await expect(
+      distribute([creator.publicKey, cohost.publicKey])
+    ).rejects.toThrow(/FeeRecipientMismatch/);
+    await expect(distribute([creator.publicKey])).rejects.toThrow(
+      /FeeRecipientMismatch/
+    );
+  });
+
+  it("timelocks changes to an existing split", async () => {
+    await setFeeSplit([{ wallet: creator.publicKey, shareBps: 10_000 }]);
+    await setFeeSplit([
+      { wallet: creator.publicKey, shareBps: 5_000 },
+      { wallet: editor.publicKey, shareBps: 5_000 },
+    ]);
+
+    let split = await ctx.program.account.feeSplit.fetch(feeSplitPda);
+    expect(split.recipients.length).toBe(1);
+    expect(split.pendingRecipients.length).toBe(2);
+
+    const apply = () =>
+      ctx.program.methods
+        .applyFeeSplit()
+        .accounts({ feeSplit: feeSplitPda })
+        .rpc();
+
+    await expect(apply()).rejects.toThrow(/FeeSplitTimelockActive/);
+
+    await advanceClock(ctx.context, 3 * SECONDS_PER_DAY);
+    await apply();
+
+    split = await ctx.program.account.feeSplit.fetch(feeSplitPda);
+    expect(split.recipients.length).toBe(2);
+    expect(split.pendingRecipients.length).toBe(0);
+    expect(split.pendingEffectiveAt.toNumber()).toBe(0);
+
+    await expect(apply()).rejects.toThrow(/NoPendingFeeSplit/);
+  });
+
+  it("only lets the creator set a valid split", async () => {
+    await expect(
+      setFeeSplit([{ wallet: editor.publicKey, shareBps: 10_000 }], editor)
+    ).rejects.toThrow(/Unauthorized/);
+    await expect(
+      setFeeSplit([
+        { wallet: creator.publicKey, shareBps: 6_000 },
+        { wallet: editor.publicKey, shareBps: 3_000 },
+      ])
+    ).rejects.toThrow(/InvalidFeeSplit/);
+    await expect(
+      setFeeSplit([
+        { wallet: editor.publicKey, shareBps: 5_000 },
+        { wallet: editor.publicKey, shareBps: 5_000 },
+      ])
+    ).rejects.toThrow(/InvalidFeeSplit/);
+    await expect(setFeeSplit([])).rejects.toThrow(/InvalidFeeSplit/);
+  });
+});
@@ -1,6 +1,9 @@
 import { Program } from "@coral-xyz/anchor";
 import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 import BN from "bn.js";
 import { Baremint } from "../target/types/baremint";
 import {
@@ -11,6 +14,7 @@ import {
   airdropSol,
   getTokenAccounts,
   calculateFee,
+  splitFee,
   DEFAULT_FEE_BPS,
   TestContext,
 } from "./setup";
@@ -23,6 +27,7 @@ describe("fees", () => {
   let bondingCurvePda: PublicKey;
   let traderAta: PublicKey;
   let accounts: ReturnType<typeof getTokenAccounts>;
+  let feeSplitPda: PublicKey;
 
   beforeEach(async () => {
     ctx = await setupTest();
@@ -33,6 +38,10 @@ describe("fees", () => {
     tokenMint = result.tokenMint;
     bondingCurvePda = result.bondingCurvePda;
     accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+    [feeSplitPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_split"), tokenMint.publicKey.toBuffer()],
+      ctx.program.programId
+    );
 
     // Setup trader
     trader = Keypair.generate();
@@ -48,15 +57,19 @@ describe("fees", () => {
     // Execute some trades to accumulate fees
     // Buy 3 SOL worth
     await ctx.program.methods
-      .buy(new BN(3_000_000_000), new BN(0))
+      .buy(new BN(3_000_000_000), new BN(0), null)
       .accounts({
         buyer: trader.publicKey,
+        payer: trader.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: traderAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([trader])
@@ -69,14 +82,16 @@ describe("fees", () => {
     const sellAmount = tokensHeld / BigInt(2);
 
     await ctx.program.methods
-      .sell(new BN(sellAmount.toString()), new BN(0))
+      .sell(new BN(sellAmount.toString()), new BN(0), null)
       .accounts({
         seller: trader.publicKey,
+        payer: null,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         sellerTokenAccount: traderAta,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -170,6 +185,7 @@ describe("fees", () => {
         creator: authority.publicKey,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
+        feeSplit: feeSplitPda,
       })
       .signers([authority])
       .rpc();
@@ -195,6 +211,7 @@ describe("fees", () => {
           creator: nonCreator.publicKey,
           bondingCurve: bondingCurvePda,
           tokenMint: tokenMint.publicKey,
+          feeSplit: feeSplitPda,
         })
         .signers([nonCreator])
         .rpc()
@@ -211,15 +228,19 @@ describe("fees", () => {
     // Do another buy
     const buyAmount = new BN(1_000_000_000);
     await ctx.program.methods
-      .buy(buyAmount, new BN(0))
+      .buy(buyAmount, new BN(0), null)
       .accounts({
         buyer: trader.publicKey,
+        payer: trader.publicKey,
         globalConfig: accounts.globalConfigPda,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
         curveTokenAccount: accounts.curveTokenAccountPda,
         buyerTokenAccount: traderAta,
+        launchBuys: null,
+        referrer: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([trader])
@@ -237,7 +258,7 @@ describe("fees", () => {
 
     // Check the increase matches expected fee from the buy
     const buyFee = calculateFee(BigInt(buyAmount.toString()), DEFAULT_FEE_BPS);
-    const expectedPlatformIncrease = buyFee / BigInt(2);
+    const expectedPlatformIncrease = splitFee(buyFee).platformFee;
     const expectedCreatorIncrease = buyFee - expectedPlatformIncrease;
 
     expect(platformAfter - platformMid).toBe(expectedPlatformIncrease);
@@ -264,6 +285,7 @@ describe("fees", () => {
         creator: authority.publicKey,
         bondingCurve: bondingCurvePda,
         tokenMint: tokenMint.publicKey,
+        feeSplit: feeSplitPda,
       })
       .signers([authority])
       .rpc();
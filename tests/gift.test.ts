@@ -0,0 +1,193 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  createATA,
+  airdropSol,
+  getTokenAccounts,
+  contentIdFor,
+  getAccessReceiptPda,
+  getPremiumContentPda,
+  calculateTokensForSolValue,
+  TestContext,
+} from "./setup";
+
+describe("burn_for_access_gift", () => {
+  let ctx: TestContext;
+  let payer: Keypair;
+  let beneficiary: Keypair;
+  let tokenMint: Keypair;
+  let bondingCurvePda: PublicKey;
+  let payerAta: PublicKey;
+  let accounts: ReturnType<typeof getTokenAccounts>;
+
+  const BURN_SOL_PRICE = new BN(100_000_000); // 0.1 SOL
+  const CONTENT_ID = contentIdFor("gifted-post");
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    const authority = ctx.context.payer;
+    await initializeGlobalConfig(ctx.program, authority);
+
+    const result = await createToken(
+      ctx.program,
+      ctx.context,
+      authority,
+      BURN_SOL_PRICE
+    );
+    tokenMint = result.tokenMint;
+    bondingCurvePda = result.bondingCurvePda;
+    accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+    payer = Keypair.generate();
+    beneficiary = Keypair.generate();
+    await airdropSol(ctx.context, payer.publicKey, 10 * 1_000_000_000);
+    payerAta = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      payer.publicKey,
+      payer
+    );
+    await ctx.program.methods
+      .buy(new BN(2_000_000_000), new BN(0), null)
+      .accounts({
+        buyer: payer.publicKey,
+        payer: payer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: payerAta,
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc();
+  });
+
+  function receiptPda(): PublicKey {
+    return getAccessReceiptPda(
+      ctx.program.programId,
+      beneficiary.publicKey,
+      tokenMint.publicKey,
+      CONTENT_ID
+    );
+  }
+
+  function gift() {
+    return ctx.program.methods
+      .burnForAccessGift(beneficiary.publicKey, CONTENT_ID)
+      .accounts({
+        payer: payer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        payerTokenAccount: payerAta,
+        premiumContent: getPremiumContentPda(
+          ctx.program.programId,
+          tokenMint.publicKey,
+          CONTENT_ID
+        ),
+        accessReceipt: receiptPda(),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc();
+  }
+
+  it("burns the payer's tokens and records the beneficiary's receipt", async () => {
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    const expectedBurn = calculateTokensForSolValue(
+      BigInt(curve.virtualSolReserves.toString()),
+      BigInt(curve.virtualTokenReserves.toString()),
+      BigInt(BURN_SOL_PRICE.toString())
+    );
+    const before = Buffer.from(
+      (await ctx.context.banksClient.getAccount(payerAta))!.data
+    ).readBigUInt64LE(64);
+
+    await gift();
+
+    const after = Buffer.from(
+      (await ctx.context.banksClient.getAccount(payerAta))!.data
+    ).readBigUInt64LE(64);
+    expect(before - after).toBe(expectedBurn);
+
+    const record = await ctx.program.account.accessReceipt.fetch(receiptPda());
+    expect(record.payer.toBase58()).toBe(payer.publicKey.toBase58());
+    expect(record.viewer.toBase58()).toBe(beneficiary.publicKey.toBase58());
+    expect(record.tokenMint.toBase58()).toBe(tokenMint.publicKey.toBase58());
+    expect(record.tokensBurned.toString()).toBe(expectedBurn.toString());
+    expect(record.solValue.toString()).toBe(BURN_SOL_PRICE.toString());
+  });
+
+  it("rejects a second gift of the same content to the same beneficiary", async () => {
+    await gift();
+    await expect(gift()).rejects.toThrow();
+  });
+
+  it("rejects a self-burn of content the beneficiary was already gifted", async () => {
+    await gift();
+
+    // Beneficiary buys tokens of their own and tries to unlock the same content
+    await airdropSol(ctx.context, beneficiary.publicKey, 10 * 1_000_000_000);
+    const beneficiaryAta = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      beneficiary.publicKey,
+      beneficiary
+    );
+    await ctx.program.methods
+      .buy(new BN(1_000_000_000), new BN(0), null)
+      .accounts({
+        buyer: beneficiary.publicKey,
+        payer: beneficiary.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: beneficiaryAta,
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([beneficiary])
+      .rpc();
+
+    await expect(
+      ctx.program.methods
+        .burnForAccess(CONTENT_ID)
+        .accounts({
+          viewer: beneficiary.publicKey,
+          globalConfig: accounts.globalConfigPda,
+          bondingCurve: bondingCurvePda,
+          tokenMint: tokenMint.publicKey,
+          viewerTokenAccount: beneficiaryAta,
+          premiumContent: getPremiumContentPda(
+            ctx.program.programId,
+            tokenMint.publicKey,
+            CONTENT_ID
+          ),
+          accessReceipt: receiptPda(),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([beneficiary])
+        .rpc()
+    ).rejects.toThrow(/already in use/);
+  });
+});
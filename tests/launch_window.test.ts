@@ -0,0 +1,134 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  airdropSol,
+  advanceClock,
+  getTokenAccounts,
+  EMPTY_CONFIG_PARAMS,
+  TestContext,
+} from "./setup";
+
+describe("launch window", () => {
+  let ctx: TestContext;
+  let buyer: Keypair;
+  let tokenMint: Keypair;
+  let bondingCurvePda: PublicKey;
+  let accounts: ReturnType<typeof getTokenAccounts>;
+
+  const WINDOW_SECONDS = 600;
+  const MAX_TOKENS_PER_WALLET = new BN("20000000000000"); // 20M tokens
+  const MAX_SOL_PER_TX = new BN(1_000_000_000); // 1 SOL
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    const authority = ctx.context.payer;
+    const globalConfigPda = await initializeGlobalConfig(
+      ctx.program,
+      authority
+    );
+
+    await ctx.program.methods
+      .updateConfig({
+        ...EMPTY_CONFIG_PARAMS,
+        launchWindowSeconds: new BN(WINDOW_SECONDS),
+        launchMaxTokensPerWallet: MAX_TOKENS_PER_WALLET,
+        launchMaxSolPerTx: MAX_SOL_PER_TX,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        globalConfig: globalConfigPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const result = await createToken(ctx.program, ctx.context, authority);
+    tokenMint = result.tokenMint;
+    bondingCurvePda = result.bondingCurvePda;
+    accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+    buyer = Keypair.generate();
+    await airdropSol(ctx.context, buyer.publicKey, 100 * 1_000_000_000);
+  });
+
+  function launchBuysPda(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("launch_buys"),
+        tokenMint.publicKey.toBuffer(),
+        buyer.publicKey.toBuffer(),
+      ],
+      ctx.program.programId
+    );
+    return pda;
+  }
+
+  function buy(solAmount: number, withLaunchBuys: boolean = true) {
+    return ctx.program.methods
+      .buy(new BN(solAmount), new BN(0), null)
+      .accounts({
+        buyer: buyer.publicKey,
+        payer: buyer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: getAssociatedTokenAddressSync(
+          tokenMint.publicKey,
+          buyer.publicKey
+        ),
+        launchBuys: withLaunchBuys ? launchBuysPda() : null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  it("stores the window and caps on the curve", async () => {
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.launchWindowEndsAt.toNumber()).toBeGreaterThan(0);
+    expect(curve.maxTokensPerWallet.toString()).toBe(
+      MAX_TOKENS_PER_WALLET.toString()
+    );
+    expect(curve.maxSolPerTx.toString()).toBe(MAX_SOL_PER_TX.toString());
+  });
+
+  it("tracks per-wallet buys inside the window", async () => {
+    await buy(200_000_000);
+
+    const tally = await ctx.program.account.launchBuys.fetch(launchBuysPda());
+    expect(tally.buyer.toBase58()).toBe(buyer.publicKey.toBase58());
+    expect(tally.tokensBought.toNumber()).toBeGreaterThan(0);
+  });
+
+  it("requires the launch_buys account inside the window", async () => {
+    await expect(buy(200_000_000, false)).rejects.toThrow(
+      /LaunchBuysAccountRequired/i
+    );
+  });
+
+  it("rejects buys above the per-transaction SOL cap", async () => {
+    await expect(buy(2_000_000_000)).rejects.toThrow(/LaunchSolPerTxExceeded/i);
+  });
+
+  it("rejects buys that push the wallet over its cap", async () => {
+    // ~0.5 SOL buys ~16.6M tokens; a second one exceeds the 20M cap
+    await buy(500_000_000);
+    await expect(buy(500_000_000)).rejects.toThrow(/LaunchWalletCapExceeded/i);
+  });
+
+  it("lifts all limits after the window closes", async () => {
+    await advanceClock(ctx.context, WINDOW_SECONDS);
+    await buy(5_000_000_000, false);
+  });
+});
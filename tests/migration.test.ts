@@ -0,0 +1,348 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { createHash } from "crypto";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  createATA,
+  airdropSol,
+  getTokenAccounts,
+  EMPTY_CONFIG_PARAMS,
+  DEFAULT_FEE_BPS,
+  DEFAULT_PLATFORM_FEE_BPS,
+  DEFAULT_CREATOR_FEE_BPS,
+  DEFAULT_VIRTUAL_TOKEN_RESERVES,
+  DEFAULT_VIRTUAL_SOL_RESERVES,
+  TestContext,
+} from "./setup";
+
+/** Anchor account discriminator: first 8 bytes of sha256("account:<Name>") */
+function discriminator(name: string): Buffer {
+  return createHash("sha256")
+    .update(`account:${name}`)
+    .digest()
+    .subarray(0, 8);
+}
+
+/** GlobalConfig bytes as written by the original program, ending at `bump` */
+function legacyConfigData(authority: PublicKey, bump: number): Buffer {
+  const data = Buffer.alloc(97);
+  let offset = 0;
+  discriminator("GlobalConfig").copy(data, offset);
+  offset += 8;
+  authority.toBuffer().copy(data, offset);
+  offset += 32;
+  offset = data.writeUInt16LE(DEFAULT_FEE_BPS, offset);
+  offset = data.writeUInt16LE(DEFAULT_PLATFORM_FEE_BPS, offset);
+  offset = data.writeUInt16LE(DEFAULT_CREATOR_FEE_BPS, offset);
+  offset = data.writeBigUInt64LE(
+    BigInt(DEFAULT_VIRTUAL_TOKEN_RESERVES.toString()),
+    offset
+  );
+  offset = data.writeBigUInt64LE(
+    BigInt(DEFAULT_VIRTUAL_SOL_RESERVES.toString()),
+    offset
+  );
+  offset = data.writeBigInt64LE(BigInt(1_296_000), offset); // 15-day cliff
+  offset = data.writeBigInt64LE(BigInt(5_184_000), offset); // 60-day duration
+  offset = data.writeBigInt64LE(BigInt(604_800), offset); // 7-day interval
+  offset = data.writeBigInt64LE(BigInt(7_776_000), offset); // 90-day cooldown
+  offset = data.writeUInt16LE(1500, offset); // 15% allocation
+  data.writeUInt8(bump, offset);
+  return data;
+}
+
+/** BondingCurve bytes as written by the original program, ending at `bump` */
+function legacyCurveData(curve: any): Buffer {
+  const data = Buffer.alloc(137);
+  let offset = 0;
+  discriminator("BondingCurve").copy(data, offset);
+  offset += 8;
+  curve.tokenMint.toBuffer().copy(data, offset);
+  offset += 32;
+  curve.creator.toBuffer().copy(data, offset);
+  offset += 32;
+  for (const value of [
+    curve.virtualTokenReserves,
+    curve.virtualSolReserves,
+    curve.realTokenReserves,
+    curve.realSolReserves,
+    curve.tokenTotalSupply,
+    curve.burnSolPrice,
+    curve.platformFeesAccrued,
+    curve.creatorFeesAccrued,
+  ]) {
+    offset = data.writeBigUInt64LE(BigInt(value.toString()), offset);
+  }
+  data.writeUInt8(curve.bump, offset);
+  return data;
+}
+
+/** VestingAccount bytes as written by the original program, ending at `bump` */
+function legacyVestingData(vesting: any): Buffer {
+  const data = Buffer.alloc(98);
+  let offset = 0;
+  discriminator("VestingAccount").copy(data, offset);
+  offset += 8;
+  vesting.creator.toBuffer().copy(data, offset);
+  offset += 32;
+  vesting.tokenMint.toBuffer().copy(data, offset);
+  offset += 32;
+  offset = data.writeBigUInt64LE(
+    BigInt(vesting.totalAllocation.toString()),
+    offset
+  );
+  offset = data.writeBigUInt64LE(
+    BigInt(vesting.claimedAmount.toString()),
+    offset
+  );
+  offset = data.writeBigInt64LE(
+    BigInt(vesting.startTimestamp.toString()),
+    offset
+  );
+  offset = data.writeUInt8(vesting.isRevoked ? 1 : 0, offset);
+  data.writeUInt8(vesting.bump, offset);
+  return data;
+}
+
+describe("account migration", () => {
+  let ctx: TestContext;
+  let authority: Keypair;
+  let globalConfigPda: PublicKey;
+  let configBump: number;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    authority = ctx.context.payer;
+    [globalConfigPda, configBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("global_config")],
+      ctx.program.programId
+    );
+  });
+
+  async function writeLegacyConfig() {
+    const data = legacyConfigData(authority.publicKey, configBump);
+    const rent = await ctx.context.banksClient.getRent();
+    ctx.context.setAccount(globalConfigPda, {
+      lamports: Number(rent.minimumBalance(BigInt(data.length))),
+      data,
+      owner: ctx.program.programId,
+      executable: false,
+    });
+  }
+
+  async function migrateConfig(signer: Keypair) {
+    await ctx.program.methods
+      .migrateConfig()
+      .accounts({
+        authority: signer.publicKey,
+        globalConfig: globalConfigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  describe("global config", () => {
+    it("cannot be loaded in the original layout until migrated", async () => {
+      await writeLegacyConfig();
+
+      await expect(
+        ctx.program.methods
+          .updateConfig({ ...EMPTY_CONFIG_PARAMS, feeBps: DEFAULT_FEE_BPS })
+          .accounts({
+            authority: authority.publicKey,
+            globalConfig: globalConfigPda,
+          })
+          .signers([authority])
+          .rpc()
+      ).rejects.toThrow();
+    });
+
+    it("grows an original-layout config and fills the appended defaults", async () => {
+      await writeLegacyConfig();
+      await migrateConfig(authority);
+
+      const info = await ctx.context.banksClient.getAccount(globalConfigPda);
+      const rent = await ctx.context.banksClient.getRent();
+      expect(info!.lamports).toBeGreaterThanOrEqual(
+        rent.minimumBalance(BigInt(info!.data.length))
+      );
+
+      const config = await ctx.program.account.globalConfig.fetch(
+        globalConfigPda
+      );
+      // Original fields keep their values
+      expect(config.authority.toBase58()).toBe(authority.publicKey.toBase58());
+      expect(config.feeBps).toBe(DEFAULT_FEE_BPS);
+      expect(config.initialVirtualSolReserves.toString()).toBe(
+        DEFAULT_VIRTUAL_SOL_RESERVES.toString()
+      );
+      expect(config.vestingCliffSeconds.toNumber()).toBe(1_296_000);
+      expect(config.creatorAllocationBps).toBe(1500);
+      expect(config.bump).toBe(configBump);
+      // Appended fields take the initialize defaults, widened to admit the
+      // existing vesting defaults
+      expect(config.paused).toBe(false);
+      expect(config.unstakeCooldownSeconds.toNumber()).toBe(604_800);
+      expect(config.feeSplitTimelockSeconds.toNumber()).toBe(259_200);
+      expect(config.maxCreatorAllocationBps).toBe(1500);
+      expect(config.minVestingCliffSeconds.toNumber()).toBe(1_296_000);
+      expect(config.minVestingDurationSeconds.toNumber()).toBe(5_184_000);
+
+      // And the config is usable again
+      await ctx.program.methods
+        .updateConfig({
+          ...EMPTY_CONFIG_PARAMS,
+          launchCooldownSeconds: new BN(0),
+        })
+        .accounts({
+          authority: authority.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("rejects migration by a key other than the authority", async () => {
+      await writeLegacyConfig();
+      const other = Keypair.generate();
+      await airdropSol(ctx.context, other.publicKey, 1_000_000_000);
+
+      await expect(migrateConfig(other)).rejects.toThrow();
+    });
+
+    it("rejects migrating a config already in the current layout", async () => {
+      await initializeGlobalConfig(ctx.program, authority);
+
+      await expect(migrateConfig(authority)).rejects.toThrow();
+    });
+  });
+
+  describe("bonding curve and vesting", () => {
+    let tokenMint: Keypair;
+    let accounts: ReturnType<typeof getTokenAccounts>;
+    let buyer: Keypair;
+    let buyerAta: PublicKey;
+
+    beforeEach(async () => {
+      await initializeGlobalConfig(ctx.program, authority);
+      const result = await createToken(ctx.program, ctx.context, authority);
+      tokenMint = result.tokenMint;
+      accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+      buyer = Keypair.generate();
+      await airdropSol(ctx.context, buyer.publicKey, 100 * 1_000_000_000);
+      buyerAta = await createATA(
+        ctx.context,
+        ctx.provider,
+        tokenMint.publicKey,
+        buyer.publicKey,
+        buyer
+      );
+      await buy(1_000_000_000);
+    });
+
+    function buy(solAmount: number) {
+      return ctx.program.methods
+        .buy(new BN(solAmount), new BN(0), null)
+        .accounts({
+          buyer: buyer.publicKey,
+          payer: buyer.publicKey,
+          globalConfig: accounts.globalConfigPda,
+          bondingCurve: accounts.bondingCurvePda,
+          tokenMint: tokenMint.publicKey,
+          curveTokenAccount: accounts.curveTokenAccountPda,
+          buyerTokenAccount: buyerAta,
+          launchBuys: null,
+          referrer: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+    }
+
+    async function writeLegacyLaunch() {
+      const curve = await ctx.program.account.bondingCurve.fetch(
+        accounts.bondingCurvePda
+      );
+      const vesting = await ctx.program.account.vestingAccount.fetch(
+        accounts.vestingAccountPda
+      );
+      for (const [pda, data] of [
+        [accounts.bondingCurvePda, legacyCurveData(curve)],
+        [accounts.vestingAccountPda, legacyVestingData(vesting)],
+      ] as [PublicKey, Buffer][]) {
+        const info = await ctx.context.banksClient.getAccount(pda);
+        ctx.context.setAccount(pda, {
+          lamports: info!.lamports,
+          data,
+          owner: ctx.program.programId,
+          executable: false,
+        });
+      }
+      return { curve, vesting };
+    }
+
+    async function migrateCurve(signer: Keypair) {
+      await ctx.program.methods
+        .migrateCurve()
+        .accounts({
+          authority: signer.publicKey,
+          globalConfig: accounts.globalConfigPda,
+          tokenMint: tokenMint.publicKey,
+          bondingCurve: accounts.bondingCurvePda,
+          vestingAccount: accounts.vestingAccountPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    it("cannot trade on an original-layout curve until migrated", async () => {
+      await writeLegacyLaunch();
+
+      await expect(buy(1_000_000_000)).rejects.toThrow();
+    });
+
+    it("restores the curve and vesting state an original launch implies", async () => {
+      const before = await writeLegacyLaunch();
+      await migrateCurve(authority);
+
+      const curve = await ctx.program.account.bondingCurve.fetch(
+        accounts.bondingCurvePda
+      );
+      const vesting = await ctx.program.account.vestingAccount.fetch(
+        accounts.vestingAccountPda
+      );
+
+      // A default constant product launch carries nothing an original launch
+      // lacked, so every field round-trips
+      expect(JSON.stringify(curve)).toBe(JSON.stringify(before.curve));
+      expect(JSON.stringify(vesting)).toBe(JSON.stringify(before.vesting));
+      expect(curve.tokensSold.gt(new BN(0))).toBe(true);
+
+      // And trading resumes
+      await buy(1_000_000_000);
+    });
+
+    it("rejects migration by a key other than the authority", async () => {
+      await writeLegacyLaunch();
+      const other = Keypair.generate();
+      await airdropSol(ctx.context, other.publicKey, 1_000_000_000);
+
+      await expect(migrateCurve(other)).rejects.toThrow();
+    });
+
+    it("rejects migrating a launch already in the current layout", async () => {
+      await expect(migrateCurve(authority)).rejects.toThrow();
+    });
+  });
+});
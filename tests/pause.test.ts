@@ -0,0 +1,163 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  createATA,
+  airdropSol,
+  getTokenAccounts,
+  TestContext,
+} from "./setup";
+
+describe("pause and trading status", () => {
+  let ctx: TestContext;
+  let buyer: Keypair;
+  let tokenMint: Keypair;
+  let bondingCurvePda: PublicKey;
+  let buyerAta: PublicKey;
+  let accounts: ReturnType<typeof getTokenAccounts>;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    const authority = ctx.context.payer;
+    await initializeGlobalConfig(ctx.program, authority);
+
+    const result = await createToken(ctx.program, ctx.context, authority);
+    tokenMint = result.tokenMint;
+    bondingCurvePda = result.bondingCurvePda;
+    accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+    buyer = Keypair.generate();
+    await airdropSol(ctx.context, buyer.publicKey, 100 * 1_000_000_000);
+    buyerAta = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      buyer.publicKey,
+      buyer
+    );
+  });
+
+  async function setPaused(paused: boolean) {
+    const authority = ctx.context.payer;
+    await ctx.program.methods
+      .setPaused(paused)
+      .accounts({
+        authority: authority.publicKey,
+        globalConfig: accounts.globalConfigPda,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  async function setTradingStatus(tradingStatus: object) {
+    const authority = ctx.context.payer;
+    await ctx.program.methods
+      .setTradingStatus(tradingStatus as any)
+      .accounts({
+        authority: authority.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  function buy(solAmount: number) {
+    return ctx.program.methods
+      .buy(new BN(solAmount), new BN(0), null)
+      .accounts({
+        buyer: buyer.publicKey,
+        payer: buyer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: buyerAta,
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  function sell(tokenAmount: bigint) {
+    return ctx.program.methods
+      .sell(new BN(tokenAmount.toString()), new BN(0), null)
+      .accounts({
+        seller: buyer.publicKey,
+        payer: null,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        sellerTokenAccount: buyerAta,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  async function tokenBalance(ata: PublicKey): Promise<bigint> {
+    const acct = await ctx.context.banksClient.getAccount(ata);
+    return Buffer.from(acct!.data).readBigUInt64LE(64);
+  }
+
+  it("new curves start active", async () => {
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.tradingStatus).toEqual({ active: {} });
+  });
+
+  it("global pause blocks trading until lifted", async () => {
+    await setPaused(true);
+    await expect(buy(1_000_000_000)).rejects.toThrow();
+
+    await setPaused(false);
+    await buy(1_000_000_000);
+    expect(await tokenBalance(buyerAta)).toBeGreaterThan(BigInt(0));
+  });
+
+  it("sells-only mode allows exits but blocks buys", async () => {
+    await buy(1_000_000_000);
+    const balance = await tokenBalance(buyerAta);
+
+    await setTradingStatus({ sellsOnly: {} });
+    await expect(buy(1_000_000_000)).rejects.toThrow();
+
+    await sell(balance);
+    expect(await tokenBalance(buyerAta)).toBe(BigInt(0));
+  });
+
+  it("halted curve blocks both buys and sells", async () => {
+    await buy(1_000_000_000);
+    const balance = await tokenBalance(buyerAta);
+
+    await setTradingStatus({ halted: {} });
+    await expect(buy(1_000_000_000)).rejects.toThrow();
+    await expect(sell(balance)).rejects.toThrow();
+  });
+
+  it("rejects pause from a non-authority signer", async () => {
+    await expect(
+      ctx.program.methods
+        .setPaused(true)
+        .accounts({
+          authority: buyer.publicKey,
+          globalConfig: accounts.globalConfigPda,
+        })
+        .signers([buyer])
+        .rpc()
+    ).rejects.toThrow();
+  });
+});
@@ -0,0 +1,269 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  createATA,
+  airdropSol,
+  advanceClock,
+  getTokenAccounts,
+  contentIdFor,
+  getAccessReceiptPda,
+  getPremiumContentPda,
+  SECONDS_PER_DAY,
+  TestContext,
+} from "./setup";
+
+describe("premium content pricing", () => {
+  let ctx: TestContext;
+  let creator: Keypair;
+  let viewer: Keypair;
+  let tokenMint: Keypair;
+  let bondingCurvePda: PublicKey;
+  let viewerAta: PublicKey;
+  let accounts: ReturnType<typeof getTokenAccounts>;
+
+  const CURVE_BURN_PRICE = new BN(100_000_000); // 0.1 SOL
+  const CONTENT_ID = contentIdFor("premium-post");
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    creator = ctx.context.payer;
+    await initializeGlobalConfig(ctx.program, creator);
+
+    const result = await createToken(
+      ctx.program,
+      ctx.context,
+      creator,
+      CURVE_BURN_PRICE
+    );
+    tokenMint = result.tokenMint;
+    bondingCurvePda = result.bondingCurvePda;
+    accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+    viewer = Keypair.generate();
+    await airdropSol(ctx.context, viewer.publicKey, 10 * 1_000_000_000);
+    viewerAta = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      viewer.publicKey,
+      viewer
+    );
+    await ctx.program.methods
+      .buy(new BN(2_000_000_000), new BN(0), null)
+      .accounts({
+        buyer: viewer.publicKey,
+        payer: viewer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: viewerAta,
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([viewer])
+      .rpc();
+  });
+
+  function contentPda(contentId: number[] = CONTENT_ID): PublicKey {
+    return getPremiumContentPda(
+      ctx.program.programId,
+      tokenMint.publicKey,
+      contentId
+    );
+  }
+
+  function register(
+    price: BN,
+    maxUnlocks: number = 0,
+    expiresAt: BN = new BN(0)
+  ) {
+    return ctx.program.methods
+      .registerPremiumContent(CONTENT_ID, price, maxUnlocks, expiresAt)
+      .accounts({
+        creator: creator.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        premiumContent: contentPda(),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([creator])
+      .rpc();
+  }
+
+  function burn(burner: Keypair, burnerAta: PublicKey) {
+    return ctx.program.methods
+      .burnForAccess(CONTENT_ID)
+      .accounts({
+        viewer: burner.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        viewerTokenAccount: burnerAta,
+        premiumContent: contentPda(),
+        accessReceipt: getAccessReceiptPda(
+          ctx.program.programId,
+          burner.publicKey,
+          tokenMint.publicKey,
+          CONTENT_ID
+        ),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([burner])
+      .rpc();
+  }
+
+  it("prices the burn from the registered content", async () => {
+    await register(new BN(300_000_000));
+    await burn(viewer, viewerAta);
+
+    const receipt = await ctx.program.account.accessReceipt.fetch(
+      getAccessReceiptPda(
+        ctx.program.programId,
+        viewer.publicKey,
+        tokenMint.publicKey,
+        CONTENT_ID
+      )
+    );
+    expect(receipt.solValue.toNumber()).toBe(300_000_000);
+
+    const content = await ctx.program.account.premiumContent.fetch(
+      contentPda()
+    );
+    expect(content.unlockCount).toBe(1);
+  });
+
+  it("falls back to the curve price when no content is registered", async () => {
+    await burn(viewer, viewerAta);
+
+    const receipt = await ctx.program.account.accessReceipt.fetch(
+      getAccessReceiptPda(
+        ctx.program.programId,
+        viewer.publicKey,
+        tokenMint.publicKey,
+        CONTENT_ID
+      )
+    );
+    expect(receipt.solValue.toString()).toBe(CURVE_BURN_PRICE.toString());
+  });
+
+  it("enforces the max unlock count", async () => {
+    await register(new BN(100_000_000), 1);
+    await burn(viewer, viewerAta);
+
+    const viewer2 = Keypair.generate();
+    await airdropSol(ctx.context, viewer2.publicKey, 10 * 1_000_000_000);
+    const viewer2Ata = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      viewer2.publicKey,
+      viewer2
+    );
+    await ctx.program.methods
+      .buy(new BN(2_000_000_000), new BN(0), null)
+      .accounts({
+        buyer: viewer2.publicKey,
+        payer: viewer2.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: viewer2Ata,
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([viewer2])
+      .rpc();
+
+    await expect(burn(viewer2, viewer2Ata)).rejects.toThrow(
+      /ContentUnlockLimitReached/i
+    );
+  });
+
+  it("rejects unlocks after expiry", async () => {
+    const clock = await ctx.context.banksClient.getClock();
+    const expiresAt = new BN((clock.unixTimestamp + BigInt(SECONDS_PER_DAY)).toString());
+    await register(new BN(100_000_000), 0, expiresAt);
+
+    await advanceClock(ctx.context, 2 * SECONDS_PER_DAY);
+    await expect(burn(viewer, viewerAta)).rejects.toThrow(/ContentExpired/i);
+  });
+
+  it("rejects an expiry that has already passed", async () => {
+    const clock = await ctx.context.banksClient.getClock();
+    const past = new BN((clock.unixTimestamp - BigInt(1)).toString());
+    await expect(register(new BN(100_000_000), 0, past)).rejects.toThrow(
+      /InvalidContentExpiry/
+    );
+
+    await register(new BN(100_000_000));
+    await expect(
+      ctx.program.methods
+        .updatePremiumContent(new BN(100_000_000), 0, past)
+        .accounts({
+          creator: creator.publicKey,
+          globalConfig: accounts.globalConfigPda,
+          bondingCurve: bondingCurvePda,
+          tokenMint: tokenMint.publicKey,
+          premiumContent: contentPda(),
+        })
+        .signers([creator])
+        .rpc()
+    ).rejects.toThrow(/InvalidContentExpiry/);
+  });
+
+  it("creator can reprice registered content", async () => {
+    await register(new BN(100_000_000));
+
+    await ctx.program.methods
+      .updatePremiumContent(new BN(200_000_000), 10, new BN(0))
+      .accounts({
+        creator: creator.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        premiumContent: contentPda(),
+      })
+      .signers([creator])
+      .rpc();
+
+    const content = await ctx.program.account.premiumContent.fetch(
+      contentPda()
+    );
+    expect(content.burnSolPrice.toNumber()).toBe(200_000_000);
+    expect(content.maxUnlocks).toBe(10);
+  });
+
+  it("rejects registration by a non-creator", async () => {
+    await expect(
+      ctx.program.methods
+        .registerPremiumContent(CONTENT_ID, new BN(100_000_000), 0, new BN(0))
+        .accounts({
+          creator: viewer.publicKey,
+          globalConfig: accounts.globalConfigPda,
+          bondingCurve: bondingCurvePda,
+          tokenMint: tokenMint.publicKey,
+          premiumContent: contentPda(),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([viewer])
+        .rpc()
+    ).rejects.toThrow();
+  });
+});
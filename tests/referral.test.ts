@@ -0,0 +1,182 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  airdropSol,
+  getTokenAccounts,
+  calculateFee,
+  splitFee,
+  DEFAULT_FEE_BPS,
+  EMPTY_CONFIG_PARAMS,
+  TestContext,
+} from "./setup";
+
+describe("referral fees", () => {
+  let ctx: TestContext;
+  let authority: Keypair;
+  let buyer: Keypair;
+  let referrerWallet: Keypair;
+  let referrerPda: PublicKey;
+  let tokenMint: Keypair;
+  let bondingCurvePda: PublicKey;
+  let accounts: ReturnType<typeof getTokenAccounts>;
+
+  const REFERRAL_FEE_BPS = 2_000; // 20% of the platform share
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    authority = ctx.context.payer;
+    const globalConfigPda = await initializeGlobalConfig(
+      ctx.program,
+      authority
+    );
+
+    await ctx.program.methods
+      .updateConfig({
+        ...EMPTY_CONFIG_PARAMS,
+        referralFeeBps: REFERRAL_FEE_BPS,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        globalConfig: globalConfigPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const result = await createToken(ctx.program, ctx.context, authority);
+    tokenMint = result.tokenMint;
+    bondingCurvePda = result.bondingCurvePda;
+    accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+    buyer = Keypair.generate();
+    await airdropSol(ctx.context, buyer.publicKey, 50 * 1_000_000_000);
+
+    referrerWallet = Keypair.generate();
+    await airdropSol(ctx.context, referrerWallet.publicKey, 1_000_000_000);
+    [referrerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("referrer"), referrerWallet.publicKey.toBuffer()],
+      ctx.program.programId
+    );
+    await ctx.program.methods
+      .registerReferrer()
+      .accounts({
+        authority: referrerWallet.publicKey,
+        referrer: referrerPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([referrerWallet])
+      .rpc();
+  });
+
+  function buy(solAmount: number, referrer: PublicKey | null) {
+    return ctx.program.methods
+      .buy(new BN(solAmount), new BN(0), null)
+      .accounts({
+        buyer: buyer.publicKey,
+        payer: buyer.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: getAssociatedTokenAddressSync(
+          tokenMint.publicKey,
+          buyer.publicKey
+        ),
+        launchBuys: null,
+        referrer,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  it("registers a referrer with nothing accrued", async () => {
+    const referrer = await ctx.program.account.referrer.fetch(referrerPda);
+    expect(referrer.authority.toBase58()).toBe(
+      referrerWallet.publicKey.toBase58()
+    );
+    expect(referrer.feesAccrued.toNumber()).toBe(0);
+  });
+
+  it("carves the referral fee out of the platform share", async () => {
+    const solAmount = BigInt(2_000_000_000);
+    await buy(Number(solAmount), referrerPda);
+
+    const totalFee = calculateFee(solAmount, DEFAULT_FEE_BPS);
+    const { platformFee, creatorFee } = splitFee(totalFee);
+    const referralFee =
+      (platformFee * BigInt(REFERRAL_FEE_BPS)) / BigInt(10000);
+
+    const referrer = await ctx.program.account.referrer.fetch(referrerPda);
+    expect(referrer.feesAccrued.toString()).toBe(referralFee.toString());
+
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.platformFeesAccrued.toString()).toBe(
+      (platformFee - referralFee).toString()
+    );
+    expect(curve.creatorFeesAccrued.toString()).toBe(creatorFee.toString());
+  });
+
+  it("leaves the platform share intact without a referrer", async () => {
+    const solAmount = BigInt(2_000_000_000);
+    await buy(Number(solAmount), null);
+
+    const { platformFee } = splitFee(calculateFee(solAmount, DEFAULT_FEE_BPS));
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.platformFeesAccrued.toString()).toBe(platformFee.toString());
+  });
+
+  it("rejects self-referral", async () => {
+    const [selfPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("referrer"), buyer.publicKey.toBuffer()],
+      ctx.program.programId
+    );
+    await ctx.program.methods
+      .registerReferrer()
+      .accounts({
+        authority: buyer.publicKey,
+        referrer: selfPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+
+    await expect(buy(1_000_000_000, selfPda)).rejects.toThrow(/SelfReferral/);
+  });
+
+  it("lets the referrer claim accrued fees", async () => {
+    await buy(2_000_000_000, referrerPda);
+    const accrued = (await ctx.program.account.referrer.fetch(referrerPda))
+      .feesAccrued;
+
+    const balanceBefore = (await ctx.context.banksClient.getAccount(
+      referrerWallet.publicKey
+    ))!.lamports;
+
+    await ctx.program.methods
+      .claimReferralFees()
+      .accounts({
+        authority: referrerWallet.publicKey,
+        referrer: referrerPda,
+      })
+      .signers([referrerWallet])
+      .rpc();
+
+    const balanceAfter = (await ctx.context.banksClient.getAccount(
+      referrerWallet.publicKey
+    ))!.lamports;
+    expect(balanceAfter - balanceBefore).toBe(BigInt(accrued.toString()));
+
+    const referrer = await ctx.program.account.referrer.fetch(referrerPda);
+    expect(referrer.feesAccrued.toNumber()).toBe(0);
+  });
+});
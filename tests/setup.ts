@@ -1,9 +1,9 @@
 import { startAnchor, BankrunProvider } from "anchor-bankrun";
-import { Program } from "@coral-xyz/anchor";
+import { EventParser, Program } from "@coral-xyz/anchor";
 import { Keypair, PublicKey, Transaction, SystemProgram } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
-  createAssociatedTokenAccountInstruction,
+  createAssociatedTokenAccountIdempotentInstruction,
   getAssociatedTokenAddressSync,
   ASSOCIATED_TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
@@ -11,6 +11,7 @@ import { ProgramTestContext, Clock } from "solana-bankrun";
 import { Baremint } from "../target/types/baremint";
 import IDL from "../target/idl/baremint.json";
 import BN from "bn.js";
+import { createHash } from "crypto";
 
 // ------- Constants -------
 
@@ -18,6 +19,10 @@ export const PROGRAM_ID = new PublicKey(
   "FTAssMPiQ8EQUeJA4Rnu6c71maCrUCdnvGetWnVdTXTG"
 );
 
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+);
+
 export const TOTAL_SUPPLY = BigInt("1000000000000000"); // 1B with 6 decimals
 export const VESTING_AMOUNT = BigInt("100000000000000"); // 100M (10% of 1B)
 export const CURVE_AMOUNT = BigInt("900000000000000"); // 900M (90% of 1B)
@@ -29,6 +34,38 @@ export const DEFAULT_VIRTUAL_TOKEN_RESERVES = new BN("1073000000000000"); // 1.0
 export const DEFAULT_VIRTUAL_SOL_RESERVES = new BN("30000000000"); // 30 SOL
 export const SECONDS_PER_DAY = 86400;
 
+/** update_config params with every field left unchanged */
+export const EMPTY_CONFIG_PARAMS = {
+  feeBps: null,
+  platformFeeBps: null,
+  creatorFeeBps: null,
+  initialVirtualTokenReserves: null,
+  initialVirtualSolReserves: null,
+  vestingCliffSeconds: null,
+  vestingDurationSeconds: null,
+  vestingClaimIntervalSeconds: null,
+  launchCooldownSeconds: null,
+  creatorAllocationBps: null,
+  minBurnSolPrice: null,
+  maxBurnSolPrice: null,
+  burnPriceUpdateCooldownSeconds: null,
+  unstakeCooldownSeconds: null,
+  maxInitialBuyBps: null,
+  launchWindowSeconds: null,
+  launchMaxTokensPerWallet: null,
+  launchMaxSolPerTx: null,
+  referralFeeBps: null,
+  allowedCurveKinds: null,
+  minTotalSupply: null,
+  maxTotalSupply: null,
+  minTokenDecimals: null,
+  maxTokenDecimals: null,
+  maxCreatorAllocationBps: null,
+  minVestingCliffSeconds: null,
+  minVestingDurationSeconds: null,
+  feeSplitTimelockSeconds: null,
+};
+
 // ------- TypeScript Math Mirrors -------
 
 /**
@@ -70,6 +107,66 @@ export function calculateFee(amount: bigint, feeBps: number): bigint {
   return numerator / BigInt(10000);
 }
 
+/**
+ * Mirror of Rust calculate_buy_sol_for_tokens (ceiling division)
+ */
+export function calculateBuySolForTokens(
+  virtualSol: bigint,
+  virtualToken: bigint,
+  tokenAmount: bigint
+): bigint {
+  if (tokenAmount === BigInt(0)) return BigInt(0);
+  const k = virtualSol * virtualToken;
+  const newVirtualToken = virtualToken - tokenAmount;
+  const newVirtualSol = (k + newVirtualToken - BigInt(1)) / newVirtualToken;
+  return newVirtualSol - virtualSol;
+}
+
+/**
+ * Mirror of Rust calculate_sell_tokens_for_sol (minimum tokens for a gross SOL output)
+ */
+export function calculateSellTokensForSol(
+  virtualSol: bigint,
+  virtualToken: bigint,
+  solAmount: bigint
+): bigint {
+  if (solAmount === BigInt(0)) return BigInt(0);
+  const k = virtualSol * virtualToken;
+  const targetVirtualSol = virtualSol - solAmount;
+  return k / (targetVirtualSol + BigInt(1)) + BigInt(1) - virtualToken;
+}
+
+/**
+ * Mirror of Rust calculate_gross_for_net (smallest gross covering net + fee)
+ */
+export function calculateGrossForNet(netAmount: bigint, feeBps: number): bigint {
+  if (netAmount === BigInt(0)) return BigInt(0);
+  if (feeBps === 0) return netAmount;
+  const denominator = BigInt(10000 - feeBps);
+  let gross = (netAmount * BigInt(10000) + denominator - BigInt(1)) / denominator;
+  while (gross - calculateFee(gross, feeBps) < netAmount) {
+    gross += BigInt(1);
+  }
+  return gross;
+}
+
+/**
+ * Mirror of Rust split_fee (platform share floored, remainder to creator)
+ */
+export function splitFee(
+  totalFee: bigint,
+  platformFeeBps: number = DEFAULT_PLATFORM_FEE_BPS,
+  creatorFeeBps: number = DEFAULT_CREATOR_FEE_BPS
+): { platformFee: bigint; creatorFee: bigint } {
+  if (totalFee === BigInt(0)) {
+    return { platformFee: BigInt(0), creatorFee: BigInt(0) };
+  }
+  const platformFee =
+    (totalFee * BigInt(platformFeeBps)) /
+    BigInt(platformFeeBps + creatorFeeBps);
+  return { platformFee, creatorFee: totalFee - platformFee };
+}
+
 /**
  * Mirror of Rust calculate_tokens_for_sol_value (ceiling division)
  */
@@ -95,7 +192,12 @@ export interface TestContext {
  * Start bankrun with the baremint program loaded
  */
 export async function setupTest(): Promise<TestContext> {
-  const context = await startAnchor("", [], []);
+  // Metaplex Token Metadata is loaded from tests/fixtures/mpl_token_metadata.so
+  const context = await startAnchor(
+    "",
+    [{ name: "mpl_token_metadata", programId: TOKEN_METADATA_PROGRAM_ID }],
+    []
+  );
   const provider = new BankrunProvider(context);
   const program = new Program<Baremint>(IDL as any, provider);
   return { context, provider, program };
@@ -132,6 +234,136 @@ export async function initializeGlobalConfig(
   return globalConfigPda;
 }
 
+export const TEST_TOKEN_NAME = "Test Creator Token";
+export const TEST_TOKEN_SYMBOL = "TEST";
+export const TEST_TOKEN_URI = "https://baremint.test/metadata/test.json";
+
+/**
+ * Derive the Metaplex metadata PDA for a mint
+ */
+export function getMetadataPda(mint: PublicKey): PublicKey {
+  const [metadataPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("metadata"),
+      TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+      mint.toBuffer(),
+    ],
+    TOKEN_METADATA_PROGRAM_ID
+  );
+  return metadataPda;
+}
+
+/**
+ * 32-byte content identifier for a post (sha256 of its ID)
+ */
+export function contentIdFor(postId: string): number[] {
+  return Array.from(createHash("sha256").update(postId).digest());
+}
+
+/**
+ * Derive the AccessReceipt PDA for a viewer, mint and content ID
+ */
+export function getAccessReceiptPda(
+  programId: PublicKey,
+  viewer: PublicKey,
+  mint: PublicKey,
+  contentId: number[]
+): PublicKey {
+  const [receiptPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("access_receipt"),
+      viewer.toBuffer(),
+      mint.toBuffer(),
+      Buffer.from(contentId),
+    ],
+    programId
+  );
+  return receiptPda;
+}
+
+/**
+ * Derive the PremiumContent PDA for a mint and content ID
+ */
+export function getPremiumContentPda(
+  programId: PublicKey,
+  mint: PublicKey,
+  contentId: number[]
+): PublicKey {
+  const [contentPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("premium_content"), mint.toBuffer(), Buffer.from(contentId)],
+    programId
+  );
+  return contentPda;
+}
+
+/**
+ * Derive the PassTier PDA for a mint and tier index
+ */
+export function getPassTierPda(
+  programId: PublicKey,
+  mint: PublicKey,
+  tier: number
+): PublicKey {
+  const [tierPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pass_tier"), mint.toBuffer(), Buffer.from([tier])],
+    programId
+  );
+  return tierPda;
+}
+
+/**
+ * Derive the AccessPass PDA for a viewer and mint
+ */
+export function getAccessPassPda(
+  programId: PublicKey,
+  viewer: PublicKey,
+  mint: PublicKey
+): PublicKey {
+  const [passPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("access_pass"), viewer.toBuffer(), mint.toBuffer()],
+    programId
+  );
+  return passPda;
+}
+
+/**
+ * Derive the StakePosition and stake vault PDAs for an owner and mint
+ */
+export function getStakePdas(
+  programId: PublicKey,
+  mint: PublicKey,
+  owner: PublicKey
+): { stakePositionPda: PublicKey; stakeVaultPda: PublicKey } {
+  const [stakePositionPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("stake_position"), mint.toBuffer(), owner.toBuffer()],
+    programId
+  );
+  const [stakeVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("stake_vault"), mint.toBuffer(), owner.toBuffer()],
+    programId
+  );
+  return { stakePositionPda, stakeVaultPda };
+}
+
+/**
+ * Per-launch vesting overrides for create_token (null = platform default)
+ */
+export type VestingScheduleParams = {
+  creatorAllocationBps: number | null;
+  vestingCliffSeconds: BN | null;
+  vestingDurationSeconds: BN | null;
+  vestingClaimIntervalSeconds: BN | null;
+  beneficiaries: { wallet: PublicKey; shareBps: number }[];
+};
+
+export const DEFAULT_VESTING_SCHEDULE: VestingScheduleParams = {
+  creatorAllocationBps: null,
+  vestingCliffSeconds: null,
+  vestingDurationSeconds: null,
+  vestingClaimIntervalSeconds: null,
+  beneficiaries: [],
+};
+
 /**
  * Create a token via create_token instruction
  */
@@ -139,7 +371,15 @@ export async function createToken(
   program: Program<Baremint>,
   context: ProgramTestContext,
   creator: Keypair,
-  burnSolPrice: BN = new BN(0)
+  burnSolPrice: BN = new BN(0),
+  isMutable: boolean = true,
+  payer: Keypair = creator,
+  initialBuySol: BN = new BN(0),
+  minTokensOut: BN = new BN(0),
+  curveKind: object = { constantProduct: {} },
+  totalSupply: BN = new BN(TOTAL_SUPPLY.toString()),
+  decimals: number = 6,
+  vestingSchedule: VestingScheduleParams = DEFAULT_VESTING_SCHEDULE
 ): Promise<{ tokenMint: Keypair; bondingCurvePda: PublicKey }> {
   const tokenMint = Keypair.generate();
 
@@ -167,11 +407,26 @@ export async function createToken(
     [Buffer.from("vesting_tokens"), tokenMint.publicKey.toBuffer()],
     program.programId
   );
+  const metadataPda = getMetadataPda(tokenMint.publicKey);
+  const config = await program.account.globalConfig.fetch(globalConfigPda);
 
   await program.methods
-    .createToken(burnSolPrice)
+    .createToken({
+      burnSolPrice,
+      name: TEST_TOKEN_NAME,
+      symbol: TEST_TOKEN_SYMBOL,
+      uri: TEST_TOKEN_URI,
+      isMutable,
+      initialBuySol,
+      minTokensOut,
+      curveKind,
+      totalSupply,
+      decimals,
+      ...vestingSchedule,
+    })
     .accounts({
       creator: creator.publicKey,
+      payer: payer.publicKey,
       globalConfig: globalConfigPda,
       creatorProfile: creatorProfilePda,
       tokenMint: tokenMint.publicKey,
@@ -179,7 +434,15 @@ export async function createToken(
       curveTokenAccount: curveTokenAccountPda,
       vestingAccount: vestingAccountPda,
       vestingTokenAccount: vestingTokenAccountPda,
+      // Only a launch buy needs the creator's token account
+      creatorTokenAccount: initialBuySol.isZero()
+        ? null
+        : getAssociatedTokenAddressSync(tokenMint.publicKey, creator.publicKey),
+      metadata: metadataPda,
+      metadataUpdateAuthority: config.authority,
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
       tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       rent: PublicKey.findProgramAddressSync(
         [],
@@ -189,7 +452,9 @@ export async function createToken(
     .accounts({
       rent: new PublicKey("SysvarRent111111111111111111111111111111111"),
     })
-    .signers([creator, tokenMint])
+    .signers(
+      payer === creator ? [creator, tokenMint] : [creator, payer, tokenMint]
+    )
     .rpc();
 
   return { tokenMint, bondingCurvePda };
@@ -206,7 +471,7 @@ export async function createATA(
   payer: Keypair
 ): Promise<PublicKey> {
   const ata = getAssociatedTokenAddressSync(mint, owner);
-  const ix = createAssociatedTokenAccountInstruction(
+  const ix = createAssociatedTokenAccountIdempotentInstruction(
     payer.publicKey,
     ata,
     owner,
@@ -220,6 +485,23 @@ export async function createATA(
   return ata;
 }
 
+/**
+ * Process a transaction in bankrun and decode the program events from its logs
+ */
+export async function processAndParseEvents(
+  context: ProgramTestContext,
+  program: Program<Baremint>,
+  tx: Transaction,
+  signers: Keypair[]
+): Promise<{ name: string; data: any }[]> {
+  tx.recentBlockhash = context.lastBlockhash;
+  tx.feePayer = signers[0].publicKey;
+  tx.sign(...signers);
+  const meta = await context.banksClient.processTransaction(tx);
+  const parser = new EventParser(program.programId, program.coder);
+  return Array.from(parser.parseLogs(meta.logMessages));
+}
+
 /**
  * Airdrop SOL to an account in bankrun
  */
@@ -0,0 +1,236 @@
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  MINT_SIZE,
+  TOKEN_PROGRAM_ID,
+  createInitializeMint2Instruction,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  createATA,
+  airdropSol,
+  advanceClock,
+  getTokenAccounts,
+  getStakePdas,
+  SECONDS_PER_DAY,
+  TestContext,
+} from "./setup";
+
+describe("stake / unstake", () => {
+  let ctx: TestContext;
+  let holder: Keypair;
+  let tokenMint: Keypair;
+  let bondingCurvePda: PublicKey;
+  let holderAta: PublicKey;
+  let accounts: ReturnType<typeof getTokenAccounts>;
+  let stakePositionPda: PublicKey;
+  let stakeVaultPda: PublicKey;
+
+  const UNSTAKE_COOLDOWN = 7 * SECONDS_PER_DAY;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    const authority = ctx.context.payer;
+    await initializeGlobalConfig(ctx.program, authority);
+
+    const result = await createToken(ctx.program, ctx.context, authority);
+    tokenMint = result.tokenMint;
+    bondingCurvePda = result.bondingCurvePda;
+    accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+    holder = Keypair.generate();
+    await airdropSol(ctx.context, holder.publicKey, 10 * 1_000_000_000);
+    holderAta = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      holder.publicKey,
+      holder
+    );
+    await ctx.program.methods
+      .buy(new BN(1_000_000_000), new BN(0), null)
+      .accounts({
+        buyer: holder.publicKey,
+        payer: holder.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        curveTokenAccount: accounts.curveTokenAccountPda,
+        buyerTokenAccount: holderAta,
+        launchBuys: null,
+        referrer: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([holder])
+      .rpc();
+
+    ({ stakePositionPda, stakeVaultPda } = getStakePdas(
+      ctx.program.programId,
+      tokenMint.publicKey,
+      holder.publicKey
+    ));
+  });
+
+  async function tokenBalance(ata: PublicKey): Promise<bigint> {
+    const acct = await ctx.context.banksClient.getAccount(ata);
+    return Buffer.from(acct!.data).readBigUInt64LE(64);
+  }
+
+  function stake(amount: bigint) {
+    return ctx.program.methods
+      .stake(new BN(amount.toString()))
+      .accounts({
+        owner: holder.publicKey,
+        globalConfig: accounts.globalConfigPda,
+        bondingCurve: bondingCurvePda,
+        tokenMint: tokenMint.publicKey,
+        stakePosition: stakePositionPda,
+        stakeVault: stakeVaultPda,
+        ownerTokenAccount: holderAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([holder])
+      .rpc();
+  }
+
+  function unstake(amount: bigint) {
+    return ctx.program.methods
+      .unstake(new BN(amount.toString()))
+      .accounts({
+        owner: holder.publicKey,
+        tokenMint: tokenMint.publicKey,
+        stakePosition: stakePositionPda,
+        stakeVault: stakeVaultPda,
+        ownerTokenAccount: holderAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([holder])
+      .rpc();
+  }
+
+  it("locks tokens in the vault and records the position", async () => {
+    const balance = await tokenBalance(holderAta);
+    const amount = balance / BigInt(2);
+
+    await stake(amount);
+
+    expect(await tokenBalance(stakeVaultPda)).toBe(amount);
+    expect(await tokenBalance(holderAta)).toBe(balance - amount);
+
+    const position = await ctx.program.account.stakePosition.fetch(
+      stakePositionPda
+    );
+    expect(position.amount.toString()).toBe(amount.toString());
+    expect(position.owner.toBase58()).toBe(holder.publicKey.toBase58());
+    expect(position.unstakeCooldownSeconds.toNumber()).toBe(UNSTAKE_COOLDOWN);
+  });
+
+  it("rejects unstake before the cooldown and allows it after", async () => {
+    const amount = await tokenBalance(holderAta);
+    await stake(amount);
+
+    await expect(unstake(amount)).rejects.toThrow(/StakeLocked/i);
+
+    await advanceClock(ctx.context, UNSTAKE_COOLDOWN);
+    await unstake(amount);
+
+    expect(await tokenBalance(holderAta)).toBe(amount);
+    const position = await ctx.program.account.stakePosition.fetch(
+      stakePositionPda
+    );
+    expect(position.amount.toNumber()).toBe(0);
+  });
+
+  it("topping up restarts the lock", async () => {
+    const balance = await tokenBalance(holderAta);
+    const half = balance / BigInt(2);
+    await stake(half);
+
+    await advanceClock(ctx.context, UNSTAKE_COOLDOWN - 60);
+    await stake(balance - half);
+
+    await advanceClock(ctx.context, 120);
+    await expect(unstake(half)).rejects.toThrow(/StakeLocked/i);
+  });
+
+  it("rejects unstaking more than the position", async () => {
+    const amount = (await tokenBalance(holderAta)) / BigInt(2);
+    await stake(amount);
+    await advanceClock(ctx.context, UNSTAKE_COOLDOWN);
+
+    await expect(unstake(amount + BigInt(1))).rejects.toThrow(
+      /InsufficientTokens/i
+    );
+  });
+
+  it("rejects mints that were not launched on the platform", async () => {
+    // A plain SPL mint with no bonding curve
+    const foreignMint = Keypair.generate();
+    const rent = await ctx.context.banksClient.getRent();
+    const tx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: holder.publicKey,
+        newAccountPubkey: foreignMint.publicKey,
+        lamports: Number(rent.minimumBalance(BigInt(MINT_SIZE))),
+        space: MINT_SIZE,
+        programId: TOKEN_PROGRAM_ID,
+      }),
+      createInitializeMint2Instruction(
+        foreignMint.publicKey,
+        6,
+        holder.publicKey,
+        null
+      )
+    );
+    tx.recentBlockhash = ctx.context.lastBlockhash;
+    tx.feePayer = holder.publicKey;
+    tx.sign(holder, foreignMint);
+    await ctx.context.banksClient.processTransaction(tx);
+
+    const foreignAta = await createATA(
+      ctx.context,
+      ctx.provider,
+      foreignMint.publicKey,
+      holder.publicKey,
+      holder
+    );
+    const foreign = getTokenAccounts(
+      ctx.program.programId,
+      foreignMint.publicKey
+    );
+    const foreignStake = getStakePdas(
+      ctx.program.programId,
+      foreignMint.publicKey,
+      holder.publicKey
+    );
+
+    await expect(
+      ctx.program.methods
+        .stake(new BN(1))
+        .accounts({
+          owner: holder.publicKey,
+          globalConfig: accounts.globalConfigPda,
+          bondingCurve: foreign.bondingCurvePda,
+          tokenMint: foreignMint.publicKey,
+          stakePosition: foreignStake.stakePositionPda,
+          stakeVault: foreignStake.stakeVaultPda,
+          ownerTokenAccount: foreignAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([holder])
+        .rpc()
+    ).rejects.toThrow(/AccountNotInitialized/);
+  });
+});
@@ -0,0 +1,154 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { MintLayout } from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  getTokenAccounts,
+  EMPTY_CONFIG_PARAMS,
+  DEFAULT_VESTING_SCHEDULE,
+  DEFAULT_VIRTUAL_SOL_RESERVES,
+  DEFAULT_VIRTUAL_TOKEN_RESERVES,
+  TOTAL_SUPPLY,
+  TestContext,
+} from "./setup";
+
+describe("configurable supply and decimals", () => {
+  let ctx: TestContext;
+  let authority: Keypair;
+  let globalConfigPda: PublicKey;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    authority = ctx.context.payer;
+    globalConfigPda = await initializeGlobalConfig(ctx.program, authority);
+  });
+
+  function launch(totalSupply: BN, decimals: number) {
+    return createToken(
+      ctx.program,
+      ctx.context,
+      authority,
+      new BN(0),
+      true,
+      authority,
+      new BN(0),
+      new BN(0),
+      { constantProduct: {} },
+      totalSupply,
+      decimals
+    );
+  }
+
+  it("launches a 10M-supply token with reserves scaled to match", async () => {
+    const totalSupply = new BN("10000000000000"); // 10M with 6 decimals
+    const { tokenMint, bondingCurvePda } = await launch(totalSupply, 6);
+
+    const curve = await ctx.program.account.bondingCurve.fetch(bondingCurvePda);
+    expect(curve.tokenTotalSupply.toString()).toBe(totalSupply.toString());
+    expect(curve.curveSupply.toString()).toBe("9000000000000");
+    // Virtual token reserves scale with supply; virtual SOL does not
+    expect(curve.virtualTokenReserves.toString()).toBe(
+      DEFAULT_VIRTUAL_TOKEN_RESERVES.mul(totalSupply)
+        .div(new BN(TOTAL_SUPPLY.toString()))
+        .toString()
+    );
+    expect(curve.virtualSolReserves.toString()).toBe(
+      DEFAULT_VIRTUAL_SOL_RESERVES.toString()
+    );
+
+    const mint = MintLayout.decode(
+      (await ctx.context.banksClient.getAccount(tokenMint.publicKey))!.data
+    );
+    expect(mint.decimals).toBe(6);
+    expect(mint.supply.toString()).toBe(totalSupply.toString());
+  });
+
+  it("launches with 9 decimals", async () => {
+    const totalSupply = new BN("1000000000000000000"); // 1B with 9 decimals
+    const { tokenMint } = await launch(totalSupply, 9);
+
+    const mint = MintLayout.decode(
+      (await ctx.context.banksClient.getAccount(tokenMint.publicKey))!.data
+    );
+    expect(mint.decimals).toBe(9);
+  });
+
+  it("launches at the maximum supply with the maximum creator allocation", async () => {
+    const config = await ctx.program.account.globalConfig.fetch(
+      globalConfigPda
+    );
+    const { tokenMint } = await createToken(
+      ctx.program,
+      ctx.context,
+      authority,
+      new BN(0),
+      true,
+      authority,
+      new BN(0),
+      new BN(0),
+      { constantProduct: {} },
+      config.maxTotalSupply,
+      9,
+      {
+        ...DEFAULT_VESTING_SCHEDULE,
+        creatorAllocationBps: config.maxCreatorAllocationBps,
+      }
+    );
+
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+    const vesting = await ctx.program.account.vestingAccount.fetch(
+      accounts.vestingAccountPda
+    );
+    expect(vesting.totalAllocation.toString()).toBe(
+      config.maxTotalSupply
+        .muln(config.maxCreatorAllocationBps)
+        .divn(10_000)
+        .toString()
+    );
+  });
+
+  it("rejects supply or decimals outside platform limits", async () => {
+    await ctx.program.methods
+      .updateConfig({
+        ...EMPTY_CONFIG_PARAMS,
+        minTotalSupply: new BN("10000000000000"),
+        maxTotalSupply: new BN(TOTAL_SUPPLY.toString()),
+        maxTokenDecimals: 6,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        globalConfig: globalConfigPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await expect(launch(new BN("1000000000000"), 6)).rejects.toThrow(
+      /InvalidTokenSupply/
+    );
+    await expect(
+      launch(new BN(TOTAL_SUPPLY.toString()).addn(1), 6)
+    ).rejects.toThrow(/InvalidTokenSupply/);
+    await expect(launch(new BN(TOTAL_SUPPLY.toString()), 9)).rejects.toThrow(
+      /InvalidTokenSupply/
+    );
+  });
+
+  it("rejects inverted supply bounds", async () => {
+    await expect(
+      ctx.program.methods
+        .updateConfig({
+          ...EMPTY_CONFIG_PARAMS,
+          minTotalSupply: new BN(2_000_000),
+          maxTotalSupply: new BN(1_000_000),
+        })
+        .accounts({
+          authority: authority.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([authority])
+        .rpc()
+    ).rejects.toThrow(/InvalidSupplyConfiguration/);
+  });
+});
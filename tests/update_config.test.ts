@@ -0,0 +1,120 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  airdropSol,
+  DEFAULT_VIRTUAL_TOKEN_RESERVES,
+  DEFAULT_VIRTUAL_SOL_RESERVES,
+  EMPTY_CONFIG_PARAMS,
+  TestContext,
+} from "./setup";
+
+describe("update_config", () => {
+  let ctx: TestContext;
+  let globalConfigPda: PublicKey;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    globalConfigPda = await initializeGlobalConfig(
+      ctx.program,
+      ctx.context.payer
+    );
+  });
+
+  it("updates fees and vesting parameters", async () => {
+    const authority = ctx.context.payer;
+
+    await ctx.program.methods
+      .updateConfig({
+        ...EMPTY_CONFIG_PARAMS,
+        feeBps: 500,
+        platformFeeBps: 300,
+        creatorFeeBps: 200,
+        vestingCliffSeconds: new BN(86_400),
+        // The default schedule must sit within the launch bounds
+        minVestingCliffSeconds: new BN(86_400),
+        launchCooldownSeconds: new BN(0),
+      })
+      .accounts({
+        authority: authority.publicKey,
+        globalConfig: globalConfigPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const config = await ctx.program.account.globalConfig.fetch(
+      globalConfigPda
+    );
+    expect(config.feeBps).toBe(500);
+    expect(config.platformFeeBps).toBe(300);
+    expect(config.creatorFeeBps).toBe(200);
+    expect(config.vestingCliffSeconds.toNumber()).toBe(86_400);
+    expect(config.launchCooldownSeconds.toNumber()).toBe(0);
+    // Untouched fields keep their values
+    expect(config.initialVirtualTokenReserves.toString()).toBe(
+      DEFAULT_VIRTUAL_TOKEN_RESERVES.toString()
+    );
+    expect(config.initialVirtualSolReserves.toString()).toBe(
+      DEFAULT_VIRTUAL_SOL_RESERVES.toString()
+    );
+    expect(config.creatorAllocationBps).toBe(1000);
+  });
+
+  it("rejects a fee share change that breaks the fee sum", async () => {
+    const authority = ctx.context.payer;
+
+    await expect(
+      ctx.program.methods
+        .updateConfig({ ...EMPTY_CONFIG_PARAMS, platformFeeBps: 300 })
+        .accounts({
+          authority: authority.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([authority])
+        .rpc()
+    ).rejects.toThrow();
+  });
+
+  it("rejects zero reserves and zero vesting duration", async () => {
+    const authority = ctx.context.payer;
+
+    await expect(
+      ctx.program.methods
+        .updateConfig({ ...EMPTY_CONFIG_PARAMS, initialVirtualSolReserves: new BN(0) })
+        .accounts({
+          authority: authority.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([authority])
+        .rpc()
+    ).rejects.toThrow();
+
+    await expect(
+      ctx.program.methods
+        .updateConfig({ ...EMPTY_CONFIG_PARAMS, vestingDurationSeconds: new BN(0) })
+        .accounts({
+          authority: authority.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([authority])
+        .rpc()
+    ).rejects.toThrow();
+  });
+
+  it("rejects non-authority signer", async () => {
+    const attacker = Keypair.generate();
+    await airdropSol(ctx.context, attacker.publicKey, 1_000_000_000);
+
+    await expect(
+      ctx.program.methods
+        .updateConfig({ ...EMPTY_CONFIG_PARAMS, feeBps: 0, platformFeeBps: 0, creatorFeeBps: 0 })
+        .accounts({
+          authority: attacker.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([attacker])
+        .rpc()
+    ).rejects.toThrow();
+  });
+});
@@ -52,12 +52,11 @@ describe("vesting", () => {
       ctx.program.methods
         .claimVested()
         .accounts({
-          creator: authority.publicKey,
-          globalConfig: accounts.globalConfigPda,
+          beneficiary: authority.publicKey,
           vestingAccount: accounts.vestingAccountPda,
           tokenMint: tokenMint.publicKey,
           vestingTokenAccount: accounts.vestingTokenAccountPda,
-          creatorTokenAccount: creatorAta,
+          beneficiaryTokenAccount: creatorAta,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([authority])
@@ -74,12 +73,11 @@ describe("vesting", () => {
       ctx.program.methods
         .claimVested()
         .accounts({
-          creator: authority.publicKey,
-          globalConfig: accounts.globalConfigPda,
+          beneficiary: authority.publicKey,
           vestingAccount: accounts.vestingAccountPda,
           tokenMint: tokenMint.publicKey,
           vestingTokenAccount: accounts.vestingTokenAccountPda,
-          creatorTokenAccount: creatorAta,
+          beneficiaryTokenAccount: creatorAta,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([authority])
@@ -136,12 +134,11 @@ describe("vesting", () => {
     await ctx.program.methods
       .claimVested()
       .accounts({
-        creator: authority.publicKey,
-        globalConfig: accounts.globalConfigPda,
+        beneficiary: authority.publicKey,
         vestingAccount: accounts.vestingAccountPda,
         tokenMint: tokenMint.publicKey,
         vestingTokenAccount: accounts.vestingTokenAccountPda,
-        creatorTokenAccount: creatorAta,
+        beneficiaryTokenAccount: creatorAta,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([authority])
@@ -170,12 +167,11 @@ describe("vesting", () => {
     await ctx.program.methods
       .claimVested()
       .accounts({
-        creator: authority.publicKey,
-        globalConfig: accounts.globalConfigPda,
+        beneficiary: authority.publicKey,
         vestingAccount: accounts.vestingAccountPda,
         tokenMint: tokenMint.publicKey,
         vestingTokenAccount: accounts.vestingTokenAccountPda,
-        creatorTokenAccount: creatorAta,
+        beneficiaryTokenAccount: creatorAta,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([authority])
@@ -237,12 +233,11 @@ describe("vesting", () => {
     await ctx.program.methods
       .claimVested()
       .accounts({
-        creator: authority.publicKey,
-        globalConfig: accounts.globalConfigPda,
+        beneficiary: authority.publicKey,
         vestingAccount: accounts.vestingAccountPda,
         tokenMint: tokenMint.publicKey,
         vestingTokenAccount: accounts.vestingTokenAccountPda,
-        creatorTokenAccount: creatorAta,
+        beneficiaryTokenAccount: creatorAta,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([authority])
@@ -253,12 +248,11 @@ describe("vesting", () => {
       ctx.program.methods
         .claimVested()
         .accounts({
-          creator: authority.publicKey,
-          globalConfig: accounts.globalConfigPda,
+          beneficiary: authority.publicKey,
           vestingAccount: accounts.vestingAccountPda,
           tokenMint: tokenMint.publicKey,
           vestingTokenAccount: accounts.vestingTokenAccountPda,
-          creatorTokenAccount: creatorAta,
+          beneficiaryTokenAccount: creatorAta,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([authority])
@@ -279,12 +273,11 @@ describe("vesting", () => {
       ctx.program.methods
         .claimVested()
         .accounts({
-          creator: authority.publicKey,
-          globalConfig: accounts.globalConfigPda,
+          beneficiary: authority.publicKey,
           vestingAccount: accounts.vestingAccountPda,
           tokenMint: tokenMint.publicKey,
           vestingTokenAccount: accounts.vestingTokenAccountPda,
-          creatorTokenAccount: creatorAta,
+          beneficiaryTokenAccount: creatorAta,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([authority])
@@ -299,12 +292,11 @@ describe("vesting", () => {
     await ctx.program.methods
       .claimVested()
       .accounts({
-        creator: authority.publicKey,
-        globalConfig: accounts.globalConfigPda,
+        beneficiary: authority.publicKey,
         vestingAccount: accounts.vestingAccountPda,
         tokenMint: tokenMint.publicKey,
         vestingTokenAccount: accounts.vestingTokenAccountPda,
-        creatorTokenAccount: creatorAta,
+        beneficiaryTokenAccount: creatorAta,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([authority])
@@ -393,12 +385,11 @@ describe("vesting", () => {
       ctx.program.methods
         .claimVested()
         .accounts({
-          creator: authority.publicKey,
-          globalConfig: accounts.globalConfigPda,
+          beneficiary: authority.publicKey,
           vestingAccount: accounts.vestingAccountPda,
           tokenMint: tokenMint.publicKey,
           vestingTokenAccount: accounts.vestingTokenAccountPda,
-          creatorTokenAccount: creatorAta,
+          beneficiaryTokenAccount: creatorAta,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([authority])
@@ -0,0 +1,155 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  createATA,
+  airdropSol,
+  advanceClock,
+  getTokenAccounts,
+  DEFAULT_VESTING_SCHEDULE,
+  VESTING_AMOUNT,
+  TOTAL_SUPPLY,
+  SECONDS_PER_DAY,
+  TestContext,
+} from "./setup";
+
+describe("vesting beneficiaries", () => {
+  let ctx: TestContext;
+  let creator: Keypair;
+  let editor: Keypair;
+  let cohost: Keypair;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    creator = ctx.context.payer;
+    await initializeGlobalConfig(ctx.program, creator);
+
+    editor = Keypair.generate();
+    cohost = Keypair.generate();
+    await airdropSol(ctx.context, editor.publicKey, 1_000_000_000);
+    await airdropSol(ctx.context, cohost.publicKey, 1_000_000_000);
+  });
+
+  function launch(beneficiaries: { wallet: PublicKey; shareBps: number }[]) {
+    return createToken(
+      ctx.program,
+      ctx.context,
+      creator,
+      new BN(0),
+      true,
+      creator,
+      new BN(0),
+      new BN(0),
+      { constantProduct: {} },
+      new BN(TOTAL_SUPPLY.toString()),
+      6,
+      { ...DEFAULT_VESTING_SCHEDULE, beneficiaries }
+    );
+  }
+
+  async function claim(tokenMint: Keypair, wallet: Keypair): Promise<bigint> {
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+    const ata = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      wallet.publicKey,
+      wallet
+    );
+    await ctx.program.methods
+      .claimVested()
+      .accounts({
+        beneficiary: wallet.publicKey,
+        vestingAccount: accounts.vestingAccountPda,
+        tokenMint: tokenMint.publicKey,
+        vestingTokenAccount: accounts.vestingTokenAccountPda,
+        beneficiaryTokenAccount: ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([wallet])
+      .rpc();
+
+    const data = (await ctx.context.banksClient.getAccount(ata))!.data;
+    return Buffer.from(data).readBigUInt64LE(64);
+  }
+
+  it("defaults to the creator as the only beneficiary", async () => {
+    const { tokenMint } = await launch([]);
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+    const vesting = await ctx.program.account.vestingAccount.fetch(
+      accounts.vestingAccountPda
+    );
+    expect(vesting.beneficiaries.length).toBe(1);
+    expect(vesting.beneficiaries[0].share.wallet.toBase58()).toBe(
+      creator.publicKey.toBase58()
+    );
+    expect(vesting.beneficiaries[0].share.shareBps).toBe(10_000);
+  });
+
+  it("splits fully vested tokens pro rata with the remainder to the last", async () => {
+    const { tokenMint } = await launch([
+      { wallet: creator.publicKey, shareBps: 5_000 },
+      { wallet: editor.publicKey, shareBps: 3_333 },
+      { wallet: cohost.publicKey, shareBps: 1_667 },
+    ]);
+
+    await advanceClock(ctx.context, 90 * SECONDS_PER_DAY);
+
+    const creatorClaim = await claim(tokenMint, creator);
+    const editorClaim = await claim(tokenMint, editor);
+    const cohostClaim = await claim(tokenMint, cohost);
+
+    expect(creatorClaim).toBe(VESTING_AMOUNT / BigInt(2));
+    expect(editorClaim).toBe((VESTING_AMOUNT * BigInt(3_333)) / BigInt(10_000));
+    expect(creatorClaim + editorClaim + cohostClaim).toBe(VESTING_AMOUNT);
+
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+    const vesting = await ctx.program.account.vestingAccount.fetch(
+      accounts.vestingAccountPda
+    );
+    expect(vesting.claimedAmount.toString()).toBe(VESTING_AMOUNT.toString());
+    expect(vesting.beneficiaries[1].claimedAmount.toString()).toBe(
+      editorClaim.toString()
+    );
+  });
+
+  it("rejects claims from wallets outside the split", async () => {
+    const { tokenMint } = await launch([
+      { wallet: creator.publicKey, shareBps: 6_000 },
+      { wallet: editor.publicKey, shareBps: 4_000 },
+    ]);
+    await advanceClock(ctx.context, 90 * SECONDS_PER_DAY);
+
+    await expect(claim(tokenMint, cohost)).rejects.toThrow(/NotABeneficiary/);
+  });
+
+  it("rejects invalid splits", async () => {
+    // Shares must sum to 100%
+    await expect(
+      launch([
+        { wallet: creator.publicKey, shareBps: 6_000 },
+        { wallet: editor.publicKey, shareBps: 3_000 },
+      ])
+    ).rejects.toThrow(/InvalidBeneficiaries/);
+    // No duplicate wallets
+    await expect(
+      launch([
+        { wallet: editor.publicKey, shareBps: 5_000 },
+        { wallet: editor.publicKey, shareBps: 5_000 },
+      ])
+    ).rejects.toThrow(/InvalidBeneficiaries/);
+    // At most five beneficiaries
+    await expect(
+      launch(
+        Array.from({ length: 6 }, (_, i) => ({
+          wallet: Keypair.generate().publicKey,
+          shareBps: i === 0 ? 5_000 : 1_000,
+        }))
+      )
+    ).rejects.toThrow(/InvalidBeneficiaries/);
+  });
+});
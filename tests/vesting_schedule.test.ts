@@ -0,0 +1,138 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  setupTest,
+  initializeGlobalConfig,
+  createToken,
+  createATA,
+  advanceClock,
+  getTokenAccounts,
+  EMPTY_CONFIG_PARAMS,
+  DEFAULT_VESTING_SCHEDULE,
+  VestingScheduleParams,
+  TOTAL_SUPPLY,
+  SECONDS_PER_DAY,
+  TestContext,
+} from "./setup";
+
+describe("per-launch vesting schedule", () => {
+  let ctx: TestContext;
+  let authority: Keypair;
+  let globalConfigPda: PublicKey;
+
+  beforeEach(async () => {
+    ctx = await setupTest();
+    authority = ctx.context.payer;
+    globalConfigPda = await initializeGlobalConfig(ctx.program, authority);
+  });
+
+  function launch(schedule: Partial<VestingScheduleParams>) {
+    return createToken(
+      ctx.program,
+      ctx.context,
+      authority,
+      new BN(0),
+      true,
+      authority,
+      new BN(0),
+      new BN(0),
+      { constantProduct: {} },
+      new BN(TOTAL_SUPPLY.toString()),
+      6,
+      { ...DEFAULT_VESTING_SCHEDULE, ...schedule }
+    );
+  }
+
+  it("snapshots the platform defaults when the creator chooses nothing", async () => {
+    const { tokenMint } = await launch({});
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+    const vesting = await ctx.program.account.vestingAccount.fetch(
+      accounts.vestingAccountPda
+    );
+    expect(vesting.allocationBps).toBe(1000);
+    expect(vesting.cliffSeconds.toNumber()).toBe(30 * SECONDS_PER_DAY);
+    expect(vesting.durationSeconds.toNumber()).toBe(60 * SECONDS_PER_DAY);
+    expect(vesting.claimIntervalSeconds.toNumber()).toBe(7 * SECONDS_PER_DAY);
+  });
+
+  it("stores a creator-chosen schedule within bounds", async () => {
+    const { tokenMint } = await launch({
+      creatorAllocationBps: 500,
+      vestingCliffSeconds: new BN(60 * SECONDS_PER_DAY),
+      vestingDurationSeconds: new BN(120 * SECONDS_PER_DAY),
+      vestingClaimIntervalSeconds: new BN(SECONDS_PER_DAY),
+    });
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+
+    const vesting = await ctx.program.account.vestingAccount.fetch(
+      accounts.vestingAccountPda
+    );
+    expect(vesting.allocationBps).toBe(500);
+    expect(vesting.totalAllocation.toString()).toBe(
+      (TOTAL_SUPPLY / BigInt(20)).toString()
+    );
+    expect(vesting.cliffSeconds.toNumber()).toBe(60 * SECONDS_PER_DAY);
+  });
+
+  it("rejects schedules outside platform bounds", async () => {
+    await expect(launch({ creatorAllocationBps: 1001 })).rejects.toThrow(
+      /InvalidVestingSchedule/
+    );
+    await expect(
+      launch({ vestingCliffSeconds: new BN(SECONDS_PER_DAY) })
+    ).rejects.toThrow(/InvalidVestingSchedule/);
+    await expect(
+      launch({ vestingDurationSeconds: new BN(SECONDS_PER_DAY) })
+    ).rejects.toThrow(/InvalidVestingSchedule/);
+    await expect(
+      launch({ vestingClaimIntervalSeconds: new BN(0) })
+    ).rejects.toThrow(/InvalidVestingSchedule/);
+  });
+
+  it("ignores later config changes when claiming", async () => {
+    const { tokenMint } = await launch({});
+    const accounts = getTokenAccounts(ctx.program.programId, tokenMint.publicKey);
+    const creatorAta = await createATA(
+      ctx.context,
+      ctx.provider,
+      tokenMint.publicKey,
+      authority.publicKey,
+      authority
+    );
+
+    // Platform lengthens the default cliff after launch
+    await ctx.program.methods
+      .updateConfig({
+        ...EMPTY_CONFIG_PARAMS,
+        vestingCliffSeconds: new BN(365 * SECONDS_PER_DAY),
+      })
+      .accounts({
+        authority: authority.publicKey,
+        globalConfig: globalConfigPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    // The snapshotted 30-day cliff still applies
+    await advanceClock(ctx.context, 37 * SECONDS_PER_DAY);
+    await ctx.program.methods
+      .claimVested()
+      .accounts({
+        beneficiary: authority.publicKey,
+        vestingAccount: accounts.vestingAccountPda,
+        tokenMint: tokenMint.publicKey,
+        vestingTokenAccount: accounts.vestingTokenAccountPda,
+        beneficiaryTokenAccount: creatorAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+
+    const vesting = await ctx.program.account.vestingAccount.fetch(
+      accounts.vestingAccountPda
+    );
+    expect(vesting.claimedAmount.gtn(0)).toBe(true);
+  });
+});